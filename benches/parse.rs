@@ -0,0 +1,30 @@
+use std::fs::File;
+use std::io::Read;
+use std::time::Instant;
+
+use unityai::navmesh::NavMeshData;
+
+const ROUNDS: u32 = 20;
+
+fn main() {
+    for path in &["tests/Navmesh.asset.txt", "tests/CSZ.asset.txt"] {
+        let mut file = File::open(path).expect("open file");
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer).expect("read_to_string");
+
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            let data: NavMeshData =
+                unityai::serde::from_str(buffer.as_str()).expect("deserialize NavMeshData");
+            assert!(!data.m_NavMeshTiles.is_empty());
+        }
+        let elapsed = start.elapsed() / ROUNDS;
+        let mb = buffer.len() as f64 / (1024.0 * 1024.0);
+        println!(
+            "{}: {:?} per parse, {:.1} MB/s",
+            path,
+            elapsed,
+            mb / elapsed.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use unityai::navmesh::NavMeshData;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = NavMeshData::from_bytes(data);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use unityai::navmesh::NavMeshData;
+
+fuzz_target!(|data: NavMeshData| {
+    let bytes = data.to_bytes().expect("to_bytes");
+    let decoded = NavMeshData::from_bytes(&bytes).expect("from_bytes");
+    assert_eq!(format!("{:?}", data), format!("{:?}", decoded));
+});
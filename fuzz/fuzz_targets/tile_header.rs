@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use unityai::navmesh::NavMeshTileData;
+
+fuzz_target!(|tile: NavMeshTileData| {
+    let _ = tile.header();
+});
@@ -0,0 +1,25 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use unityai::serde::UnityDeError;
+
+use super::OutputFormat;
+
+pub fn run(asset: &Path, to: OutputFormat, output: Option<&Path>) -> Result<(), UnityDeError> {
+    let data = super::load_navmesh(asset)?;
+    let bytes = match to {
+        OutputFormat::Text => unityai::serde::to_string(&data)?.into_bytes(),
+        OutputFormat::Json => super::to_json(&data)?,
+        OutputFormat::Bin => data.to_bytes()?,
+    };
+    match output {
+        Some(path) => {
+            let mut writer = BufWriter::new(File::create(path)?);
+            writer.write_all(&bytes)?;
+            writer.flush()?;
+        }
+        None => std::io::stdout().lock().write_all(&bytes)?,
+    }
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use unityai::serde::UnityDeError;
+use unityai::testing::run_corpus;
+
+/// Parses every dump in `dir`, returning false on a failure which isn't expected by
+/// the baseline. With `update` the baseline is rewritten from this run instead.
+pub fn run(dir: &Path, baseline: Option<&Path>, update: bool) -> Result<bool, UnityDeError> {
+    let report = run_corpus(dir)?;
+    for entry in &report.entries {
+        match &entry.error {
+            None => println!("ok   {:>9.3}ms {}", ms(entry.elapsed), entry.name),
+            Some(err) => println!("FAIL {:>9.3}ms {}: {}", ms(entry.elapsed), entry.name, err),
+        }
+    }
+    let failures = report.failures().count();
+    println!(
+        "{} assets, {} failed, {:.3}ms total",
+        report.entries.len(),
+        failures,
+        ms(report.total_time())
+    );
+
+    let baseline = match baseline {
+        Some(baseline) => baseline,
+        None => return Ok(failures == 0),
+    };
+    if update {
+        std::fs::write(baseline, report.to_baseline())?;
+        println!("baseline {} updated", baseline.display());
+        return Ok(true);
+    }
+    let comparison = report.compare(&std::fs::read_to_string(baseline)?);
+    for name in &comparison.regressions {
+        println!("regression: {}", name);
+    }
+    for name in &comparison.fixed {
+        println!("fixed: {}", name);
+    }
+    for name in &comparison.missing {
+        println!("missing: {}", name);
+    }
+    Ok(!comparison.is_regression())
+}
+
+fn ms(duration: std::time::Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
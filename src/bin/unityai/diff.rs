@@ -0,0 +1,12 @@
+use std::path::Path;
+
+use unityai::serde::UnityDeError;
+
+/// Returns whether the two assets are equivalent.
+pub fn run(old: &Path, new: &Path) -> Result<bool, UnityDeError> {
+    let old = super::load_navmesh(old)?;
+    let new = super::load_navmesh(new)?;
+    let diff = old.diff(&new);
+    print!("{}", diff);
+    Ok(diff.is_empty())
+}
@@ -0,0 +1,139 @@
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use serde::de::Error;
+
+use unityai::navmesh::NavMeshData;
+use unityai::serde::{UnityDeError, Vector3f};
+
+use super::ExtractFormat;
+
+/// Which part of the asset `extract` keeps.
+#[derive(Clone, Copy)]
+pub enum Component {
+    Tile { x: i32, y: i32, layer: Option<i32> },
+    HeightMesh(usize),
+    Links,
+}
+
+/// Parses `x,y` or `x,y,layer` as given to `--tile`.
+pub fn parse_tile(value: &str) -> Result<Component, String> {
+    let coords = value
+        .split(',')
+        .map(|v| v.trim().parse::<i32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("{}: {}", value, e))?;
+    match coords[..] {
+        [x, y] => Ok(Component::Tile { x, y, layer: None }),
+        [x, y, layer] => Ok(Component::Tile {
+            x,
+            y,
+            layer: Some(layer),
+        }),
+        _ => Err(format!("expected x,y or x,y,layer, got {}", value)),
+    }
+}
+
+/// Writes the asset stripped down to `component`.
+///
+/// Text, JSON and the compact format keep a complete NavMeshData with everything
+/// else removed, so the result loads like any other asset. OBJ writes the geometry
+/// of the component itself.
+pub fn run(
+    asset: &Path,
+    component: Component,
+    format: ExtractFormat,
+    output: Option<&Path>,
+) -> Result<(), UnityDeError> {
+    let mut data = super::load_navmesh(asset)?;
+    match component {
+        Component::Tile { x, y, layer } => {
+            data.m_NavMeshTiles.retain(|tile| {
+                tile.header().is_some_and(|header| {
+                    header.x == x && header.y == y && layer.is_none_or(|l| header.layer == l)
+                })
+            });
+            if data.m_NavMeshTiles.is_empty() {
+                return Err(UnityDeError::custom(format!("no tile at {},{}", x, y)));
+            }
+            data.m_HeightMeshes.clear();
+            data.m_OffMeshLinks.clear();
+        }
+        Component::HeightMesh(index) => {
+            if index >= data.m_HeightMeshes.len() {
+                return Err(UnityDeError::custom(format!(
+                    "no height mesh {}, the asset has {}",
+                    index,
+                    data.m_HeightMeshes.len()
+                )));
+            }
+            let mesh = data.m_HeightMeshes.swap_remove(index);
+            data.m_HeightMeshes = vec![mesh];
+            data.m_NavMeshTiles.clear();
+            data.m_OffMeshLinks.clear();
+        }
+        Component::Links => {
+            data.m_NavMeshTiles.clear();
+            data.m_HeightMeshes.clear();
+        }
+    }
+
+    let bytes = match format {
+        ExtractFormat::Text => unityai::serde::to_string(&data)?.into_bytes(),
+        ExtractFormat::Json => super::to_json(&data)?,
+        ExtractFormat::Bin => data.to_bytes()?,
+        ExtractFormat::Obj => to_obj(&data, component)?.into_bytes(),
+    };
+    match output {
+        Some(path) => {
+            let mut writer = BufWriter::new(File::create(path)?);
+            writer.write_all(&bytes)?;
+            writer.flush()?;
+        }
+        None => std::io::stdout().lock().write_all(&bytes)?,
+    }
+    Ok(())
+}
+
+fn to_obj(data: &NavMeshData, component: Component) -> Result<String, UnityDeError> {
+    let mut obj = String::new();
+    match component {
+        Component::Tile { .. } => {
+            return Err(UnityDeError::custom(
+                "tile polygons are not decoded, extract tiles as text, json or bin",
+            ))
+        }
+        Component::HeightMesh(_) => {
+            for mesh in &data.m_HeightMeshes {
+                for v in &mesh.m_Vertices {
+                    write_vertex(&mut obj, v);
+                }
+                let count = mesh.m_Vertices.len();
+                if let Some(index) = mesh.m_Indices.iter().find(|&&i| i as usize >= count) {
+                    return Err(UnityDeError::custom(format!(
+                        "index {} out of range for {} vertices",
+                        index, count
+                    )));
+                }
+                for face in mesh.m_Indices.chunks_exact(3) {
+                    let [a, b, c] = [face[0], face[1], face[2]].map(|i| u64::from(i) + 1);
+                    writeln!(obj, "f {} {} {}", a, b, c).unwrap();
+                }
+            }
+        }
+        Component::Links => {
+            for (i, link) in data.m_OffMeshLinks.iter().enumerate() {
+                write_vertex(&mut obj, &link.m_Start);
+                write_vertex(&mut obj, &link.m_End);
+                writeln!(obj, "l {} {}", 2 * i + 1, 2 * i + 2).unwrap();
+            }
+        }
+    }
+    Ok(obj)
+}
+
+fn write_vertex(obj: &mut String, v: &Vector3f) {
+    writeln!(obj, "v {} {} {}", v.x(), v.y(), v.z()).unwrap();
+}
@@ -0,0 +1,103 @@
+use std::path::Path;
+
+use unityai::navmesh::{NavMeshBuildSettings, NavMeshData};
+use unityai::serde::UnityDeError;
+
+pub fn run(asset: &Path) -> Result<(), UnityDeError> {
+    let data = super::load_navmesh(asset)?;
+    print_summary(&data);
+    Ok(())
+}
+
+fn print_summary(data: &NavMeshData) {
+    println!("NavMeshData \"{}\"", data.m_Name);
+    println!("  agent type id: {}", data.m_AgentTypeID);
+    println!("  position:      {}", data.m_Position);
+    println!("  rotation:      {}", data.m_Rotation);
+    println!("  bounds min:    {}", data.m_SourceBounds.min());
+    println!("  bounds max:    {}", data.m_SourceBounds.max());
+
+    print_settings(&data.m_NavMeshBuildSettings);
+    print_tiles(data);
+
+    println!("Height meshes: {}", data.m_HeightMeshes.len());
+    for (i, mesh) in data.m_HeightMeshes.iter().enumerate() {
+        println!(
+            "  #{}: {} vertices, {} triangles, {} BV nodes",
+            i,
+            mesh.m_Vertices.len(),
+            mesh.m_Indices.len() / 3,
+            mesh.m_Nodes.len()
+        );
+    }
+
+    println!("Off-mesh links: {}", data.m_OffMeshLinks.len());
+    for (i, link) in data.m_OffMeshLinks.iter().enumerate() {
+        println!(
+            "  #{}: {} -> {}, radius {}, type {}, area {}, direction {}",
+            i,
+            link.m_Start,
+            link.m_End,
+            link.m_Radius,
+            link.m_LinkType,
+            link.m_Area,
+            link.m_LinkDirection
+        );
+    }
+}
+
+fn print_settings(settings: &NavMeshBuildSettings) {
+    println!("Build settings");
+    println!("  agentTypeID:           {}", settings.agentTypeID);
+    println!("  agentRadius:           {}", settings.agentRadius);
+    println!("  agentHeight:           {}", settings.agentHeight);
+    println!("  agentSlope:            {}", settings.agentSlope);
+    println!("  agentClimb:            {}", settings.agentClimb);
+    println!("  ledgeDropHeight:       {}", settings.ledgeDropHeight);
+    println!(
+        "  maxJumpAcrossDistance: {}",
+        settings.maxJumpAcrossDistance
+    );
+    println!("  minRegionArea:         {}", settings.minRegionArea);
+    println!("  manualCellSize:        {}", settings.manualCellSize);
+    println!("  cellSize:              {}", settings.cellSize);
+    println!("  manualTileSize:        {}", settings.manualTileSize);
+    println!("  tileSize:              {}", settings.tileSize);
+    println!("  accuratePlacement:     {}", settings.accuratePlacement);
+}
+
+fn print_tiles(data: &NavMeshData) {
+    let tiles = &data.m_NavMeshTiles;
+    let bytes: usize = tiles.iter().map(|t| t.m_MeshData.len()).sum();
+    println!("Tiles: {}, {} bytes of mesh data", tiles.len(), bytes);
+
+    let headers: Vec<_> = tiles.iter().filter_map(|t| t.header()).collect();
+    if headers.len() != tiles.len() {
+        println!(
+            "  {} tiles without a valid header",
+            tiles.len() - headers.len()
+        );
+    }
+    if let (Some(min_x), Some(max_x), Some(min_y), Some(max_y), Some(max_layer)) = (
+        headers.iter().map(|h| h.x).min(),
+        headers.iter().map(|h| h.x).max(),
+        headers.iter().map(|h| h.y).min(),
+        headers.iter().map(|h| h.y).max(),
+        headers.iter().map(|h| h.layer).max(),
+    ) {
+        println!(
+            "  grid: x {}..={}, y {}..={}, {} layer(s)",
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+            max_layer + 1
+        );
+    }
+    if let (Some(min), Some(max)) = (
+        tiles.iter().map(|t| t.m_MeshData.len()).min(),
+        tiles.iter().map(|t| t.m_MeshData.len()).max(),
+    ) {
+        println!("  tile size: {}..={} bytes", min, max);
+    }
+}
@@ -0,0 +1,194 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
+use serde::de::Error;
+
+use unityai::navmesh::NavMeshData;
+use unityai::serde::UnityDeError;
+
+mod convert;
+mod corpus;
+mod diff;
+mod extract;
+mod inspect;
+mod validate;
+mod watch;
+
+#[derive(Parser)]
+#[command(name = "unityai", version, about = "Inspect Unity navmesh assets")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print a human readable summary of a navmesh asset
+    Inspect {
+        /// NavMeshData text dump, compact runtime file or JSON
+        asset: PathBuf,
+    },
+    /// Convert a navmesh asset to another format
+    Convert {
+        /// NavMeshData text dump, compact runtime file or JSON
+        asset: PathBuf,
+        #[arg(long, value_enum)]
+        to: OutputFormat,
+        /// Output file, stdout if omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Compare two navmesh assets, exiting non-zero if they differ
+    Diff {
+        /// The old asset
+        old: PathBuf,
+        /// The new asset
+        new: PathBuf,
+    },
+    /// Parse and check a navmesh asset, exiting non-zero on any error
+    Validate {
+        /// NavMeshData text dump, compact runtime file or JSON
+        asset: PathBuf,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Parse every navmesh dump below a directory and compare the outcome with a baseline
+    CheckCorpus {
+        /// Directory searched recursively for NavMeshData text dumps
+        dir: PathBuf,
+        /// Baseline of expected outcomes, without it any failure is an error
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Rewrite the baseline from this run
+        #[arg(long, requires = "baseline")]
+        update: bool,
+    },
+    /// Write a single tile, height mesh or the off-mesh links of a navmesh asset
+    #[command(group(ArgGroup::new("component").required(true)))]
+    Extract {
+        /// NavMeshData text dump, compact runtime file or JSON
+        asset: PathBuf,
+        /// Tile at x,y or x,y,layer
+        #[arg(long, group = "component", value_parser = extract::parse_tile)]
+        tile: Option<extract::Component>,
+        /// Height mesh by index
+        #[arg(long, group = "component")]
+        heightmesh: Option<usize>,
+        /// The off-mesh links
+        #[arg(long, group = "component")]
+        links: bool,
+        #[arg(long, value_enum, default_value_t = ExtractFormat::Text)]
+        format: ExtractFormat,
+        /// Output file, stdout if omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Re-parse and validate dumps whenever they change, printing what changed
+    Watch {
+        /// A navmesh asset, or a directory whose NavMeshData dumps are watched
+        target: PathBuf,
+        /// Polling interval in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// Unity text dump
+    Text,
+    /// serde JSON of the parsed model, NaN and infinite floats are refused
+    Json,
+    /// the crate's compact runtime format
+    Bin,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExtractFormat {
+    /// Unity text dump
+    Text,
+    /// serde JSON of the parsed model, NaN and infinite floats are refused
+    Json,
+    /// the crate's compact runtime format
+    Bin,
+    /// Wavefront OBJ of the component's geometry
+    Obj,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Inspect { asset } => inspect::run(&asset).map(|_| true),
+        Command::Convert { asset, to, output } => {
+            convert::run(&asset, to, output.as_deref()).map(|_| true)
+        }
+        Command::Diff { old, new } => diff::run(&old, &new),
+        Command::Validate { asset, format } => validate::run(&asset, format),
+        Command::CheckCorpus {
+            dir,
+            baseline,
+            update,
+        } => corpus::run(&dir, baseline.as_deref(), update),
+        Command::Extract {
+            asset,
+            tile,
+            heightmesh,
+            links: _,
+            format,
+            output,
+        } => {
+            let component = tile
+                .or(heightmesh.map(extract::Component::HeightMesh))
+                .unwrap_or(extract::Component::Links);
+            extract::run(&asset, component, format, output.as_deref()).map(|_| true)
+        }
+        Command::Watch { target, interval } => watch::run(&target, Duration::from_millis(interval)),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Loads a navmesh from a text dump, the compact runtime format or JSON.
+///
+/// The whole file is read first, dumps may be rewritten by Unity while they are parsed.
+fn load_navmesh(path: &Path) -> Result<NavMeshData, UnityDeError> {
+    let data = std::fs::read(path)?;
+    if data.starts_with(b"UAIN") {
+        NavMeshData::from_bytes(&data)
+    } else if path.extension().is_some_and(|ext| ext == "json") {
+        serde_json::from_slice(&data).map_err(UnityDeError::custom)
+    } else {
+        let text = std::str::from_utf8(&data).map_err(UnityDeError::custom)?;
+        unityai::serde::from_str(text)
+    }
+}
+
+/// Writes a navmesh as pretty printed JSON.
+///
+/// JSON has no NaN or infinity and serde_json writes them as `null`, which no
+/// longer loads as a float. Such assets are refused instead of written lossy.
+fn to_json(data: &NavMeshData) -> Result<Vec<u8>, UnityDeError> {
+    let json = serde_json::to_vec_pretty(data).map_err(UnityDeError::custom)?;
+    serde_json::from_slice::<NavMeshData>(&json).map_err(|err| {
+        UnityDeError::custom(format!(
+            "JSON cannot represent NaN or infinite floats, written as null at line {} column {}",
+            err.line(),
+            err.column()
+        ))
+    })?;
+    Ok(json)
+}
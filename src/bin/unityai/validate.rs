@@ -0,0 +1,70 @@
+use std::path::Path;
+
+use serde::de::Error;
+use serde::Serialize;
+
+use unityai::serde::UnityDeError;
+
+#[derive(Serialize)]
+struct Report<'a> {
+    asset: &'a str,
+    valid: bool,
+    errors: Vec<Record>,
+}
+
+#[derive(Serialize)]
+struct Record {
+    kind: &'static str,
+    message: String,
+    /// where a parse error was found, validation errors have no position
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+}
+
+/// Returns whether the asset is valid, reporting every problem on stdout.
+pub fn run(asset: &Path, format: super::Format) -> Result<bool, UnityDeError> {
+    let errors = match super::load_navmesh(asset) {
+        Ok(data) => data
+            .validate()
+            .into_iter()
+            .map(|err| Record {
+                kind: err.kind(),
+                message: err.to_string(),
+                line: None,
+                column: None,
+            })
+            .collect(),
+        Err(err) => vec![Record {
+            kind: "parse",
+            message: err.to_string(),
+            line: err.location().map(|location| location.line),
+            column: err.location().map(|location| location.column),
+        }],
+    };
+
+    let name = asset.to_string_lossy();
+    let report = Report {
+        asset: &name,
+        valid: errors.is_empty(),
+        errors,
+    };
+    match format {
+        super::Format::Text => {
+            for record in &report.errors {
+                println!("{}: {}: {}", report.asset, record.kind, record.message);
+            }
+            if report.valid {
+                println!("{}: ok", report.asset);
+            }
+        }
+        super::Format::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&report).map_err(UnityDeError::custom)?
+            );
+        }
+    }
+    Ok(report.valid)
+}
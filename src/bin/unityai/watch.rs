@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use unityai::navmesh::{is_navmesh_dump, NavMeshData};
+use unityai::serde::UnityDeError;
+
+struct Watched {
+    modified: SystemTime,
+    /// last version which parsed, diffs are printed against it
+    data: Option<NavMeshData>,
+}
+
+/// Polls `target` every `interval`, re-parsing and validating each dump when it changes
+/// and printing the diff against the last version which parsed. Only returns on error.
+pub fn run(target: &Path, interval: Duration) -> Result<bool, UnityDeError> {
+    let mut watched: BTreeMap<PathBuf, Watched> = BTreeMap::new();
+    println!("watching {}", target.display());
+    loop {
+        // a dump being rewritten may not be readable for a moment, only drop it
+        // together with its last version once it is really gone
+        watched.retain(|path, _| {
+            let keep = path.exists();
+            if !keep {
+                println!("{}: removed", path.display());
+            }
+            keep
+        });
+        for path in scan(target)? {
+            watched.entry(path).or_insert(Watched {
+                modified: SystemTime::UNIX_EPOCH,
+                data: None,
+            });
+        }
+        for (path, entry) in watched.iter_mut() {
+            // the file may be in the middle of being replaced, pick it up next round
+            let modified = match std::fs::metadata(path).and_then(|meta| meta.modified()) {
+                Ok(modified) => modified,
+                Err(_) => continue,
+            };
+            if entry.modified == modified {
+                continue;
+            }
+            entry.modified = modified;
+            reload(path, entry);
+        }
+        std::thread::sleep(interval);
+    }
+}
+
+/// The dumps to watch, a file whose header can't be read right now is picked up
+/// next round.
+fn scan(target: &Path) -> Result<Vec<PathBuf>, UnityDeError> {
+    if !target.is_dir() {
+        return Ok(vec![target.to_path_buf()]);
+    }
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(target)? {
+        let path = entry?.path();
+        if path.is_file() && is_navmesh_dump(&path).unwrap_or(false) {
+            paths.push(path);
+        }
+    }
+    Ok(paths)
+}
+
+fn reload(path: &Path, entry: &mut Watched) {
+    let data = match super::load_navmesh(path) {
+        Ok(data) => data,
+        Err(err) => {
+            // a dump still being written fails too, it is parsed again on the next change
+            println!("{}: parse error: {}", path.display(), err);
+            return;
+        }
+    };
+    let errors = data.validate();
+    match &entry.data {
+        None => println!("{}: loaded", path.display()),
+        Some(old) => {
+            let diff = old.diff(&data);
+            if diff.is_empty() {
+                println!("{}: reloaded, no changes", path.display());
+            } else {
+                println!("{}: reloaded", path.display());
+                print!("{}", diff);
+            }
+        }
+    }
+    for err in &errors {
+        println!("{}: {}: {}", path.display(), err.kind(), err);
+    }
+    entry.data = Some(data);
+}
@@ -0,0 +1,34 @@
+use crate::serde::{Quaternionf, Vector3f};
+
+impl From<::glam::Vec3> for Vector3f {
+    fn from(v: ::glam::Vec3) -> Self {
+        Vector3f {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<Vector3f> for ::glam::Vec3 {
+    fn from(v: Vector3f) -> Self {
+        ::glam::Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<::glam::Quat> for Quaternionf {
+    fn from(q: ::glam::Quat) -> Self {
+        Quaternionf {
+            x: q.x,
+            y: q.y,
+            z: q.z,
+            w: q.w,
+        }
+    }
+}
+
+impl From<Quaternionf> for ::glam::Quat {
+    fn from(q: Quaternionf) -> Self {
+        ::glam::Quat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
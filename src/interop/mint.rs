@@ -0,0 +1,65 @@
+use crate::serde::{Quaternionf, Vector3f};
+
+impl From<::mint::Vector3<f32>> for Vector3f {
+    fn from(v: ::mint::Vector3<f32>) -> Self {
+        Vector3f {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<Vector3f> for ::mint::Vector3<f32> {
+    fn from(v: Vector3f) -> Self {
+        ::mint::Vector3 {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<::mint::Point3<f32>> for Vector3f {
+    fn from(p: ::mint::Point3<f32>) -> Self {
+        Vector3f {
+            x: p.x,
+            y: p.y,
+            z: p.z,
+        }
+    }
+}
+
+impl From<Vector3f> for ::mint::Point3<f32> {
+    fn from(v: Vector3f) -> Self {
+        ::mint::Point3 {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<::mint::Quaternion<f32>> for Quaternionf {
+    fn from(q: ::mint::Quaternion<f32>) -> Self {
+        Quaternionf {
+            x: q.v.x,
+            y: q.v.y,
+            z: q.v.z,
+            w: q.s,
+        }
+    }
+}
+
+impl From<Quaternionf> for ::mint::Quaternion<f32> {
+    fn from(q: Quaternionf) -> Self {
+        ::mint::Quaternion {
+            v: ::mint::Vector3 {
+                x: q.x,
+                y: q.y,
+                z: q.z,
+            },
+            s: q.w,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! Conversions between the Unity math types and the common Rust math crates.
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
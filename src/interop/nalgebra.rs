@@ -0,0 +1,56 @@
+use crate::serde::{Quaternionf, Vector3f};
+
+impl From<::nalgebra::Vector3<f32>> for Vector3f {
+    fn from(v: ::nalgebra::Vector3<f32>) -> Self {
+        Vector3f {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<Vector3f> for ::nalgebra::Vector3<f32> {
+    fn from(v: Vector3f) -> Self {
+        ::nalgebra::Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<::nalgebra::Point3<f32>> for Vector3f {
+    fn from(p: ::nalgebra::Point3<f32>) -> Self {
+        Vector3f {
+            x: p.x,
+            y: p.y,
+            z: p.z,
+        }
+    }
+}
+
+impl From<Vector3f> for ::nalgebra::Point3<f32> {
+    fn from(v: Vector3f) -> Self {
+        ::nalgebra::Point3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<::nalgebra::Quaternion<f32>> for Quaternionf {
+    fn from(q: ::nalgebra::Quaternion<f32>) -> Self {
+        Quaternionf {
+            x: q.i,
+            y: q.j,
+            z: q.k,
+            w: q.w,
+        }
+    }
+}
+
+impl From<::nalgebra::UnitQuaternion<f32>> for Quaternionf {
+    fn from(q: ::nalgebra::UnitQuaternion<f32>) -> Self {
+        q.into_inner().into()
+    }
+}
+
+impl From<Quaternionf> for ::nalgebra::Quaternion<f32> {
+    fn from(q: Quaternionf) -> Self {
+        ::nalgebra::Quaternion::new(q.w, q.x, q.y, q.z)
+    }
+}
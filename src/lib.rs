@@ -1 +1,22 @@
+/// `tracing::trace!` when the `tracing` feature is enabled, nothing otherwise.
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::trace!($($arg)*)
+    };
+}
+
+/// Enters a `tracing` span until the end of the current scope when the `tracing`
+/// feature is enabled, nothing otherwise.
+macro_rules! span {
+    ($level:ident, $($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::span!(tracing::Level::$level, $($arg)*).entered();
+    };
+}
+
+mod interop;
+pub mod navmesh;
 pub mod serde;
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -0,0 +1,62 @@
+use serde::de::Error;
+
+use super::NavMeshData;
+use crate::serde::{Result, UnityDeError};
+
+const MAGIC: &[u8; 4] = b"UAIN";
+
+/// Version of the compact runtime format written by [`NavMeshData::to_bytes`].
+pub const FORMAT_VERSION: u32 = 1;
+
+const HEADER_SIZE: usize = 8;
+
+impl NavMeshData {
+    /// Encodes the navmesh into the compact runtime format.
+    ///
+    /// Parse the text dump once with [`crate::serde::from_str`] and ship the
+    /// result of this call, [`NavMeshData::from_bytes`] loads it without
+    /// touching the text parser.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut buffer = Vec::with_capacity(HEADER_SIZE);
+        buffer.extend_from_slice(MAGIC);
+        buffer.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bincode::serialize_into(&mut buffer, self).map_err(UnityDeError::custom)?;
+        Ok(buffer)
+    }
+
+    /// Decodes a navmesh written by [`NavMeshData::to_bytes`] of this or an earlier
+    /// release of the crate.
+    pub fn from_bytes(data: &[u8]) -> Result<NavMeshData> {
+        let payload = &data[HEADER_SIZE.min(data.len())..];
+        match format_version(data)? {
+            FORMAT_VERSION => bincode::deserialize(payload).map_err(UnityDeError::custom),
+            // layouts replaced by a newer FORMAT_VERSION get an arm here, decoding
+            // into a copy of their old types and converting to the current ones
+            version => Err(UnityDeError::custom(format!(
+                "unsupported format version {}, expected {}",
+                version, FORMAT_VERSION
+            ))),
+        }
+    }
+}
+
+/// Reads the format version from the header of a buffer written by [`NavMeshData::to_bytes`].
+pub fn format_version(data: &[u8]) -> Result<u32> {
+    if data.len() < HEADER_SIZE || &data[..4] != MAGIC {
+        return Err(UnityDeError::custom("not a unityai navmesh"));
+    }
+    let mut version = [0u8; 4];
+    version.copy_from_slice(&data[4..HEADER_SIZE]);
+    Ok(u32::from_le_bytes(version))
+}
+
+/// Rewrites a buffer from an earlier release in the current [`FORMAT_VERSION`].
+///
+/// Lets a cache written by an older build be upgraded once instead of being
+/// converted on every load. A buffer which is already current is returned as is.
+pub fn migrate(data: &[u8]) -> Result<Vec<u8>> {
+    if format_version(data)? == FORMAT_VERSION {
+        return Ok(data.to_vec());
+    }
+    NavMeshData::from_bytes(data)?.to_bytes()
+}
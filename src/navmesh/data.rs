@@ -0,0 +1,58 @@
+#![allow(non_snake_case)]
+
+use serde::{Deserialize, Serialize};
+
+use super::NavMeshBuildSettings;
+use crate::serde::{Hash128, Quaternionf, Vector3f, AABB};
+
+/// A baked navmesh as stored in a `NavMesh.asset` file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct NavMeshData {
+    pub m_Name: String,
+    pub m_NavMeshTiles: Vec<NavMeshTileData>,
+    pub m_NavMeshBuildSettings: NavMeshBuildSettings,
+    pub m_HeightMeshes: Vec<HeightMeshData>,
+    pub m_OffMeshLinks: Vec<AutoOffMeshLinkData>,
+    pub m_SourceBounds: AABB,
+    pub m_Rotation: Quaternionf,
+    pub m_Position: Vector3f,
+    pub m_AgentTypeID: i32,
+}
+
+/// One encoded tile, `m_MeshData` is kept in Unity's binary layout.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct NavMeshTileData {
+    pub m_MeshData: Vec<u8>,
+    pub m_Hash: Hash128,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct HeightMeshData {
+    pub m_Vertices: Vec<Vector3f>,
+    pub m_Indices: Vec<u32>,
+    pub m_Bounds: AABB,
+    pub m_Nodes: Vec<HeightMeshBVNode>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct HeightMeshBVNode {
+    pub min: Vector3f,
+    pub max: Vector3f,
+    pub i: i32,
+    pub n: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AutoOffMeshLinkData {
+    pub m_Start: Vector3f,
+    pub m_End: Vector3f,
+    pub m_Radius: f32,
+    pub m_LinkType: u16,
+    pub m_Area: u8,
+    pub m_LinkDirection: u8,
+}
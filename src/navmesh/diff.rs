@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use super::{AutoOffMeshLinkData, NavMeshBuildSettings, NavMeshData, NavMeshTileData};
+
+/// A build setting whose value differs between two navmeshes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingChange {
+    pub name: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+/// Tile position as found in the tile header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TileCoord {
+    pub x: i32,
+    pub y: i32,
+    pub layer: i32,
+}
+
+/// Differences between two navmeshes, see [`NavMeshData::diff`].
+///
+/// Tiles are matched by their header coordinates and count as modified when
+/// their hash or mesh data differ. Tiles without a valid header are not
+/// compared, [`NavMeshData::validate`] reports them.
+#[derive(Debug, Default)]
+pub struct NavMeshDiff<'a> {
+    pub settings: Vec<SettingChange>,
+    pub added_tiles: Vec<TileCoord>,
+    pub removed_tiles: Vec<TileCoord>,
+    pub modified_tiles: Vec<TileCoord>,
+    pub added_links: Vec<&'a AutoOffMeshLinkData>,
+    pub removed_links: Vec<&'a AutoOffMeshLinkData>,
+}
+
+impl NavMeshDiff<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.settings.is_empty()
+            && self.added_tiles.is_empty()
+            && self.removed_tiles.is_empty()
+            && self.modified_tiles.is_empty()
+            && self.added_links.is_empty()
+            && self.removed_links.is_empty()
+    }
+}
+
+macro_rules! compare_settings {
+    ($changes:ident, $old:ident, $new:ident, $($field:ident),*) => {
+        $(
+            if $old.$field != $new.$field {
+                $changes.push(SettingChange {
+                    name: stringify!($field),
+                    old: $old.$field.to_string(),
+                    new: $new.$field.to_string(),
+                });
+            }
+        )*
+    };
+}
+
+fn diff_settings(old: &NavMeshBuildSettings, new: &NavMeshBuildSettings) -> Vec<SettingChange> {
+    let mut changes = Vec::new();
+    compare_settings!(
+        changes,
+        old,
+        new,
+        agentTypeID,
+        agentRadius,
+        agentHeight,
+        agentSlope,
+        agentClimb,
+        ledgeDropHeight,
+        maxJumpAcrossDistance,
+        minRegionArea,
+        manualCellSize,
+        cellSize,
+        manualTileSize,
+        tileSize,
+        accuratePlacement
+    );
+    changes
+}
+
+fn tiles_by_coord(data: &NavMeshData) -> BTreeMap<TileCoord, &NavMeshTileData> {
+    data.m_NavMeshTiles
+        .iter()
+        .filter_map(|tile| {
+            tile.header().map(|h| {
+                let coord = TileCoord {
+                    x: h.x,
+                    y: h.y,
+                    layer: h.layer,
+                };
+                (coord, tile)
+            })
+        })
+        .collect()
+}
+
+impl NavMeshData {
+    /// Compares `self` (the old navmesh) against `new`.
+    pub fn diff<'a>(&'a self, new: &'a NavMeshData) -> NavMeshDiff<'a> {
+        span!(
+            DEBUG,
+            "diff",
+            asset = %self.m_Name,
+            old_tiles = self.m_NavMeshTiles.len(),
+            new_tiles = new.m_NavMeshTiles.len()
+        );
+        let mut diff = NavMeshDiff {
+            settings: diff_settings(&self.m_NavMeshBuildSettings, &new.m_NavMeshBuildSettings),
+            ..Default::default()
+        };
+
+        let old_tiles = tiles_by_coord(self);
+        let new_tiles = tiles_by_coord(new);
+        for (coord, old_tile) in &old_tiles {
+            match new_tiles.get(coord) {
+                None => diff.removed_tiles.push(*coord),
+                Some(new_tile) => {
+                    if old_tile.m_Hash != new_tile.m_Hash
+                        || old_tile.m_MeshData != new_tile.m_MeshData
+                    {
+                        diff.modified_tiles.push(*coord);
+                    }
+                }
+            }
+        }
+        diff.added_tiles = new_tiles
+            .keys()
+            .filter(|coord| !old_tiles.contains_key(coord))
+            .copied()
+            .collect();
+
+        diff.removed_links = self
+            .m_OffMeshLinks
+            .iter()
+            .filter(|link| !new.m_OffMeshLinks.contains(link))
+            .collect();
+        diff.added_links = new
+            .m_OffMeshLinks
+            .iter()
+            .filter(|link| !self.m_OffMeshLinks.contains(link))
+            .collect();
+
+        diff
+    }
+}
+
+impl Display for TileCoord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, layer {})", self.x, self.y, self.layer)
+    }
+}
+
+impl Display for NavMeshDiff<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for change in &self.settings {
+            writeln!(
+                f,
+                "~ setting {}: {} -> {}",
+                change.name, change.old, change.new
+            )?;
+        }
+        for coord in &self.removed_tiles {
+            writeln!(f, "- tile {}", coord)?;
+        }
+        for coord in &self.added_tiles {
+            writeln!(f, "+ tile {}", coord)?;
+        }
+        for coord in &self.modified_tiles {
+            writeln!(f, "~ tile {}", coord)?;
+        }
+        for link in &self.removed_links {
+            writeln!(f, "- link {} -> {}", link.m_Start, link.m_End)?;
+        }
+        for link in &self.added_links {
+            writeln!(f, "+ link {} -> {}", link.m_Start, link.m_End)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use super::NavMeshData;
+use crate::serde::{Result, UnityDeError};
+
+/// How far [`is_navmesh_dump`] looks for the object header.
+const HEADER_LIMIT: u64 = 1 << 20;
+
+/// Result of [`load_dir`]: every navmesh that parsed, and the error of every
+/// one that did not, both sorted by path.
+#[derive(Debug, Default)]
+pub struct LoadReport {
+    pub navmeshes: Vec<(PathBuf, NavMeshData)>,
+    pub errors: Vec<(PathBuf, UnityDeError)>,
+}
+
+/// Parses all NavMeshData dumps found directly in `dir` on a pool of worker
+/// threads. Files which are not NavMeshData dumps are skipped, files whose header
+/// can't be read are reported like those which don't parse.
+pub fn load_dir<P: AsRef<Path>>(dir: P) -> Result<LoadReport> {
+    let mut paths = Vec::new();
+    let mut unreadable = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+        match is_navmesh_dump(&path) {
+            Ok(true) => paths.push(path),
+            Ok(false) => {}
+            Err(err) => unreadable.push((path, err)),
+        }
+    }
+    paths.sort();
+    span!(DEBUG, "load_dir", files = paths.len());
+
+    let workers = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(paths.len());
+    let next = AtomicUsize::new(0);
+    let report = Mutex::new(LoadReport::default());
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let path = match paths.get(index) {
+                    Some(path) => path,
+                    None => break,
+                };
+                let result = crate::serde::from_path(path);
+                let mut report = report.lock().unwrap();
+                match result {
+                    Ok(data) => report.navmeshes.push((path.clone(), data)),
+                    Err(err) => report.errors.push((path.clone(), err)),
+                }
+            });
+        }
+    });
+
+    let mut report = report.into_inner().unwrap();
+    report.errors.extend(unreadable);
+    report.navmeshes.sort_by(|a, b| a.0.cmp(&b.0));
+    report.errors.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(report)
+}
+
+/// Whether the file at `path` is a Unity text dump of a NavMeshData object, only
+/// the lines up to the first object header are read.
+pub fn is_navmesh_dump(path: &Path) -> Result<bool> {
+    //the object header follows 'External References', which may list many files
+    let mut reader = BufReader::new(File::open(path)?.take(HEADER_LIMIT));
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            return Ok(false);
+        }
+        let line = String::from_utf8_lossy(&line);
+        if line.starts_with("ID: ") {
+            return Ok(line.trim_end().ends_with(") NavMeshData"));
+        }
+    }
+}
@@ -0,0 +1,22 @@
+#[cfg(feature = "binary")]
+pub use binary::{format_version, migrate, FORMAT_VERSION};
+pub use data::{
+    AutoOffMeshLinkData, HeightMeshBVNode, HeightMeshData, NavMeshData, NavMeshTileData,
+};
+pub use diff::{NavMeshDiff, SettingChange, TileCoord};
+pub use load::{is_navmesh_dump, load_dir, LoadReport};
+pub use settings::{
+    AgentTypeRegistry, NavMeshAreaData, NavMeshBuildDebugSettings, NavMeshBuildSettings,
+    NavMeshProjectSettings,
+};
+pub use tile::TileHeader;
+pub use validate::ValidationError;
+
+#[cfg(feature = "binary")]
+mod binary;
+mod data;
+mod diff;
+mod load;
+mod settings;
+mod tile;
+mod validate;
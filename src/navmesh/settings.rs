@@ -0,0 +1,149 @@
+#![allow(non_snake_case)]
+
+use serde::{Deserialize, Serialize};
+
+/// Debug flags stored alongside the build settings, kept for completeness.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct NavMeshBuildDebugSettings {
+    pub m_Flags: u8,
+}
+
+/// Agent dependent settings used when baking a navmesh.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct NavMeshBuildSettings {
+    pub agentTypeID: i32,
+    pub agentRadius: f32,
+    pub agentHeight: f32,
+    pub agentSlope: f32,
+    pub agentClimb: f32,
+    pub ledgeDropHeight: f32,
+    pub maxJumpAcrossDistance: f32,
+    pub minRegionArea: f32,
+    pub manualCellSize: i32,
+    pub cellSize: f32,
+    pub manualTileSize: i32,
+    pub tileSize: i32,
+    pub accuratePlacement: i32,
+    pub debug: NavMeshBuildDebugSettings,
+}
+
+impl NavMeshBuildSettings {
+    /// The built-in `Humanoid` agent type with the defaults of a new Unity project.
+    pub fn humanoid() -> NavMeshBuildSettings {
+        NavMeshBuildSettings {
+            agentTypeID: 0,
+            agentRadius: 0.5,
+            agentHeight: 2.0,
+            agentSlope: 45.0,
+            agentClimb: 0.75,
+            ledgeDropHeight: 0.0,
+            maxJumpAcrossDistance: 0.0,
+            minRegionArea: 2.0,
+            manualCellSize: 0,
+            cellSize: 0.5 / 3.0,
+            manualTileSize: 0,
+            tileSize: 256,
+            accuratePlacement: 0,
+            debug: NavMeshBuildDebugSettings { m_Flags: 0 },
+        }
+    }
+}
+
+impl Default for NavMeshBuildSettings {
+    fn default() -> Self {
+        NavMeshBuildSettings::humanoid()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct NavMeshAreaData {
+    pub name: String,
+    pub cost: f32,
+}
+
+/// Content of `ProjectSettings/NavMeshAreas.asset`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct NavMeshProjectSettings {
+    pub areas: Vec<NavMeshAreaData>,
+    pub m_LastAgentTypeID: i32,
+    pub m_Settings: Vec<NavMeshBuildSettings>,
+    pub m_SettingNames: Vec<String>,
+}
+
+/// Agent types by id and name, as configured in the project's navigation settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentTypeRegistry {
+    agents: Vec<(String, NavMeshBuildSettings)>,
+}
+
+impl AgentTypeRegistry {
+    /// A registry containing only the built-in `Humanoid` agent type.
+    pub fn new() -> AgentTypeRegistry {
+        let mut registry = AgentTypeRegistry { agents: Vec::new() };
+        registry.register("Humanoid", NavMeshBuildSettings::humanoid());
+        registry
+    }
+
+    /// Adds an agent type, replacing any existing one with the same `agentTypeID`.
+    pub fn register<S: Into<String>>(&mut self, name: S, settings: NavMeshBuildSettings) {
+        let name = name.into();
+        match self
+            .agents
+            .iter_mut()
+            .find(|(_, s)| s.agentTypeID == settings.agentTypeID)
+        {
+            Some(agent) => *agent = (name, settings),
+            None => self.agents.push((name, settings)),
+        }
+    }
+
+    pub fn get(&self, agent_type_id: i32) -> Option<&NavMeshBuildSettings> {
+        self.agents
+            .iter()
+            .find(|(_, s)| s.agentTypeID == agent_type_id)
+            .map(|(_, s)| s)
+    }
+
+    pub fn get_by_name(&self, name: &str) -> Option<&NavMeshBuildSettings> {
+        self.agents.iter().find(|(n, _)| n == name).map(|(_, s)| s)
+    }
+
+    pub fn name_of(&self, agent_type_id: i32) -> Option<&str> {
+        self.agents
+            .iter()
+            .find(|(_, s)| s.agentTypeID == agent_type_id)
+            .map(|(n, _)| n.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &NavMeshBuildSettings)> {
+        self.agents.iter().map(|(n, s)| (n.as_str(), s))
+    }
+
+    pub fn len(&self) -> usize {
+        self.agents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.agents.is_empty()
+    }
+}
+
+impl Default for AgentTypeRegistry {
+    fn default() -> Self {
+        AgentTypeRegistry::new()
+    }
+}
+
+impl From<NavMeshProjectSettings> for AgentTypeRegistry {
+    fn from(project: NavMeshProjectSettings) -> Self {
+        let mut registry = AgentTypeRegistry { agents: Vec::new() };
+        for (name, settings) in project.m_SettingNames.into_iter().zip(project.m_Settings) {
+            registry.register(name, settings);
+        }
+        registry
+    }
+}
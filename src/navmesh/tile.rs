@@ -0,0 +1,32 @@
+use super::NavMeshTileData;
+
+const TILE_MAGIC: i32 = i32::from_le_bytes(*b"VAND");
+
+/// The leading fields of an encoded tile in `m_MeshData`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TileHeader {
+    pub version: i32,
+    pub x: i32,
+    pub y: i32,
+    pub layer: i32,
+}
+
+impl NavMeshTileData {
+    /// Reads the tile header, `None` if `m_MeshData` is not an encoded tile.
+    pub fn header(&self) -> Option<TileHeader> {
+        let mut fields = self
+            .m_MeshData
+            .chunks_exact(4)
+            .take(5)
+            .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]));
+        if fields.next()? != TILE_MAGIC {
+            return None;
+        }
+        Some(TileHeader {
+            version: fields.next()?,
+            x: fields.next()?,
+            y: fields.next()?,
+            layer: fields.next()?,
+        })
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use super::NavMeshData;
+
+/// A consistency problem found by [`NavMeshData::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    InvalidTileHeader {
+        tile: usize,
+    },
+    TileVersionMismatch {
+        tile: usize,
+        version: i32,
+        expected: i32,
+    },
+    DuplicateTile {
+        tile: usize,
+        first: usize,
+        x: i32,
+        y: i32,
+        layer: i32,
+    },
+    IncompleteTriangle {
+        mesh: usize,
+        indices: usize,
+    },
+    IndexOutOfRange {
+        mesh: usize,
+        index: u32,
+        vertices: usize,
+    },
+    AgentTypeMismatch {
+        data: i32,
+        settings: i32,
+    },
+}
+
+impl ValidationError {
+    /// A stable name of the error kind, for machine readable reports.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ValidationError::InvalidTileHeader { .. } => "invalid_tile_header",
+            ValidationError::TileVersionMismatch { .. } => "tile_version_mismatch",
+            ValidationError::DuplicateTile { .. } => "duplicate_tile",
+            ValidationError::IncompleteTriangle { .. } => "incomplete_triangle",
+            ValidationError::IndexOutOfRange { .. } => "index_out_of_range",
+            ValidationError::AgentTypeMismatch { .. } => "agent_type_mismatch",
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::InvalidTileHeader { tile } => {
+                write!(f, "tile {} has no valid header", tile)
+            }
+            ValidationError::TileVersionMismatch {
+                tile,
+                version,
+                expected,
+            } => write!(
+                f,
+                "tile {} has version {}, expected {}",
+                tile, version, expected
+            ),
+            ValidationError::DuplicateTile {
+                tile,
+                first,
+                x,
+                y,
+                layer,
+            } => write!(
+                f,
+                "tile {} at ({}, {}, layer {}) duplicates tile {}",
+                tile, x, y, layer, first
+            ),
+            ValidationError::IncompleteTriangle { mesh, indices } => write!(
+                f,
+                "height mesh {} has {} indices, not a multiple of 3",
+                mesh, indices
+            ),
+            ValidationError::IndexOutOfRange {
+                mesh,
+                index,
+                vertices,
+            } => write!(
+                f,
+                "height mesh {} index {} is out of range of {} vertices",
+                mesh, index, vertices
+            ),
+            ValidationError::AgentTypeMismatch { data, settings } => write!(
+                f,
+                "agent type id {} does not match build settings agent type id {}",
+                data, settings
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl NavMeshData {
+    /// Checks the parsed data for internal consistency, returning every
+    /// problem found.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        span!(DEBUG, "validate", asset = %self.m_Name, tiles = self.m_NavMeshTiles.len());
+        let mut errors = Vec::new();
+
+        if self.m_AgentTypeID != self.m_NavMeshBuildSettings.agentTypeID {
+            errors.push(ValidationError::AgentTypeMismatch {
+                data: self.m_AgentTypeID,
+                settings: self.m_NavMeshBuildSettings.agentTypeID,
+            });
+        }
+
+        let mut expected = None;
+        let mut positions = HashMap::new();
+        for (tile, data) in self.m_NavMeshTiles.iter().enumerate() {
+            let header = match data.header() {
+                Some(header) => header,
+                None => {
+                    errors.push(ValidationError::InvalidTileHeader { tile });
+                    continue;
+                }
+            };
+            span!(
+                TRACE,
+                "tile",
+                index = tile,
+                x = header.x,
+                y = header.y,
+                layer = header.layer,
+                bytes = data.m_MeshData.len()
+            );
+            let expected = *expected.get_or_insert(header.version);
+            if header.version != expected {
+                errors.push(ValidationError::TileVersionMismatch {
+                    tile,
+                    version: header.version,
+                    expected,
+                });
+            }
+            if let Some(first) = positions.insert((header.x, header.y, header.layer), tile) {
+                errors.push(ValidationError::DuplicateTile {
+                    tile,
+                    first,
+                    x: header.x,
+                    y: header.y,
+                    layer: header.layer,
+                });
+            }
+        }
+
+        for (mesh, data) in self.m_HeightMeshes.iter().enumerate() {
+            span!(
+                TRACE,
+                "height_mesh",
+                index = mesh,
+                vertices = data.m_Vertices.len(),
+                nodes = data.m_Nodes.len()
+            );
+            if data.m_Indices.len() % 3 != 0 {
+                errors.push(ValidationError::IncompleteTriangle {
+                    mesh,
+                    indices: data.m_Indices.len(),
+                });
+            }
+            let vertices = data.m_Vertices.len();
+            if let Some(index) = data.m_Indices.iter().find(|i| **i as usize >= vertices) {
+                errors.push(ValidationError::IndexOutOfRange {
+                    mesh,
+                    index: *index,
+                    vertices,
+                });
+            }
+        }
+
+        errors
+    }
+}
@@ -1,10 +1,20 @@
-use std::str::{Chars, FromStr};
-
-use regex::Regex;
-use serde::de::{DeserializeSeed, Error, Expected, MapAccess, SeqAccess, Visitor};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+use std::str::FromStr;
+
+use memchr::{memchr, memchr2, memchr3, memrchr};
+use memmap2::Mmap;
+
+use serde::de::value::{BorrowedStrDeserializer, SeqDeserializer, U32Deserializer};
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, Error, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
 use serde::{Deserialize, Deserializer};
 
-use super::UnityDeError;
+use super::defaults::DefaultDeserializer;
+use super::{Location, UnityDeError};
 
 #[derive(Copy, Clone)]
 enum DeStatus {
@@ -20,67 +30,118 @@ pub struct UnityDeserializer<'de> {
     data: &'de str,
     offset: usize,
     status: Vec<DeStatus>,
-    regex: Regex,
     root: bool,
-    type_name: String,
+    type_name: &'de str,
+    line: Cell<(usize, usize)>,
+    /// errors recovered from, only collected by `parse_all_errors`
+    errors: Option<Vec<UnityDeError>>,
+    /// fields without a serde default which `parse_all_errors` reads as zero when
+    /// missing, keyed by the name of their struct
+    synthesize: HashMap<&'static str, Vec<&'static str>>,
+    /// the first missing field without a default, which the next pass reads as zero
+    missing: Option<(&'static str, &'static str)>,
 }
 
 impl<'de> UnityDeserializer<'de> {
     fn from_str(data: &'de str) -> UnityDeserializer<'de> {
-        let mut status = Vec::new();
-        status.push(DeStatus::Invalid);
-        let regex = Regex::new(r"data \([0-9a-zA-Z ]+\) #[0-9]+:").unwrap();
+        let status = vec![DeStatus::Invalid];
         UnityDeserializer {
             data,
             tab: 0,
             offset: 0,
             root: true,
             status,
-            regex,
-            type_name: String::new(),
+            type_name: "",
+            line: Cell::new((0, 0)),
+            errors: None,
+            synthesize: HashMap::new(),
+            missing: None,
+        }
+    }
+
+    /// Records `err` and carries on when collecting errors, fails with it otherwise.
+    fn recover(&mut self, err: UnityDeError) -> super::Result<()> {
+        let location = Location::of(self.data, self.offset);
+        match &mut self.errors {
+            Some(errors) => {
+                errors.push(err.at(location));
+                Ok(())
+            }
+            None => Err(err),
         }
     }
 
+    /// Skips the current line and every line below it indented deeper than `tab`,
+    /// together with the blank lines ending the vectors and maps among them.
+    ///
+    /// Works line by line, so deeply nested input can't overflow the stack.
+    fn skip_block(&mut self, tab: usize) -> super::Result<()> {
+        let mut blanks = 0;
+        let mut first = true;
+        while !self.is_empty() {
+            let line = self.peek_line();
+            if !first {
+                if line.trim().is_empty() {
+                    if blanks == 0 {
+                        break;
+                    }
+                    blanks -= 1;
+                } else if self.tab_count() <= tab {
+                    break;
+                }
+            }
+            first = false;
+            let container = line.ends_with("(vector)") || line.ends_with("(map)");
+            self.skip(self.count_until(b'\n').min(self.remaining() - 1) + 1)?;
+            if container && !self.is_empty() {
+                //input='\t\tsize N (int)', packed rows aren't followed by a blank line
+                self.skip(self.count_until(b'\n').min(self.remaining() - 1) + 1)?;
+                if !self.is_seq_multi()? {
+                    blanks += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn current_status(&self) -> DeStatus {
         *self.status.last().unwrap()
     }
 
     fn tab_count(&self) -> usize {
-        if let Some(count) = self.chars().position(|c| c != '\t') {
-            count
-        } else {
-            self.data.len() - self.offset
-        }
+        self.bytes()
+            .iter()
+            .position(|b| *b != b'\t')
+            .unwrap_or_else(|| self.remaining())
     }
 
     fn skip_header(&mut self) -> super::Result<()> {
         let mut current_eol = 0;
         let pos = self
-            .chars()
+            .bytes()
+            .iter()
             .position(|d| {
-                if d == '\n' {
+                if *d == b'\n' {
                     current_eol += 1;
-                } else if d != '\r' {
+                } else if *d != b'\r' {
                     current_eol = 0;
                 }
                 current_eol == 3
             })
-            .ok_or_else(|| UnityDeError::custom("skip file header failed"))?;
+            .ok_or_else(|| UnityDeError::unexpected("file header", self.peek_line()))?;
         self.skip(pos + 1)
     }
 
-    fn count_until(&self, d: char) -> usize {
-        self.chars()
-            .position(|c| c == d)
-            .unwrap_or_else(|| self.remaining())
+    fn count_until(&self, d: u8) -> usize {
+        memchr(d, self.bytes()).unwrap_or_else(|| self.remaining())
     }
 
     fn remaining(&self) -> usize {
         self.data.len() - self.offset
     }
 
-    fn chars(&self) -> Chars {
-        self.data[self.offset..].chars()
+    fn bytes(&self) -> &'de [u8] {
+        &self.data.as_bytes()[self.offset..]
     }
 
     fn skip(&mut self, count: usize) -> super::Result<()> {
@@ -88,18 +149,19 @@ impl<'de> UnityDeserializer<'de> {
             self.offset += count;
             Ok(())
         } else {
-            Err(UnityDeError::Eof)
+            Err(UnityDeError::Eof { location: None })
         }
     }
 
     fn skip_tab(&mut self, count: usize) -> super::Result<()> {
-        let mut it = self.chars();
+        let mut it = self.bytes().iter();
         for _ in 0..count {
-            if it.next().ok_or_else(|| UnityDeError::Eof)? != '\t' {
-                return Err(UnityDeError::custom(format!(
-                    "tab not match:{}",
-                    self.peek_line()
-                )));
+            if *it.next().ok_or(UnityDeError::Eof { location: None })? != b'\t' {
+                return Err(UnityDeError::TabMismatch {
+                    expected: count,
+                    found: self.tab_count(),
+                    location: None,
+                });
             }
         }
         self.skip(count)
@@ -107,16 +169,13 @@ impl<'de> UnityDeserializer<'de> {
 
     fn skip_space(&mut self) -> super::Result<()> {
         if !self.next_char()?.is_ascii_whitespace() {
-            Err(UnityDeError::custom(format!(
-                "space expected at:{}",
-                self.peek_line()
-            )))
+            Err(UnityDeError::unexpected("space", self.peek_line()))
         } else {
             Ok(())
         }
     }
 
-    fn skip_until(&mut self, d: char) -> super::Result<()> {
+    fn skip_until(&mut self, d: u8) -> super::Result<()> {
         let pos = self.count_until(d);
         self.skip(pos + 1)
     }
@@ -125,13 +184,13 @@ impl<'de> UnityDeserializer<'de> {
         if let DeStatus::MultipleElement = self.current_status() {
             Ok(())
         } else {
-            self.skip_until('\n')
+            self.skip_until(b'\n')
         }
     }
 
     fn get_str(&mut self, len: usize) -> super::Result<&'de str> {
         if self.offset + len > self.data.len() {
-            Err(UnityDeError::Eof)
+            Err(UnityDeError::Eof { location: None })
         } else {
             let ret = &self.data[self.offset..self.offset + len];
             self.skip(len)?;
@@ -139,128 +198,313 @@ impl<'de> UnityDeserializer<'de> {
         }
     }
 
-    fn peek_str(&self, len: usize) -> super::Result<&'de str> {
-        if self.offset + len <= self.data.len() {
-            Ok(&self.data[self.offset..self.offset + len])
-        } else {
-            Err(UnityDeError::Eof)
-        }
-    }
-
-    fn peek_type(&mut self) -> super::Result<&str> {
+    fn peek_type(&self) -> super::Result<&'de str> {
         let line = self.peek_line();
-        let (bgn, _) = line
-            .char_indices()
-            .rfind(|(_, c)| *c == '(')
-            .ok_or_else(|| UnityDeError::custom(format!("type not found:{}", line)))?;
-        let end = line[bgn + 1..]
-            .chars()
-            .position(|c| c == ')')
-            .ok_or_else(|| UnityDeError::custom(format!("type not found:{}", line)))?;
+        let bgn = memrchr(b'(', line.as_bytes())
+            .ok_or_else(|| UnityDeError::unexpected("(type)", line))?;
+        let end = memchr(b')', &line.as_bytes()[bgn + 1..])
+            .ok_or_else(|| UnityDeError::unexpected("(type)", line))?;
         Ok(&line[bgn + 1..bgn + end + 1])
     }
 
-    fn get_identifier(&mut self) -> super::Result<&str> {
+    fn get_identifier(&mut self) -> super::Result<&'de str> {
         let pos = self
-            .chars()
-            .position(|c| !c.is_ascii_alphanumeric() && c != '_' && c != '[' && c != ']')
-            .ok_or_else(|| UnityDeError::custom("identifier not found"))?;
+            .bytes()
+            .iter()
+            .position(|c| !c.is_ascii_alphanumeric() && *c != b'_' && *c != b'[' && *c != b']')
+            .ok_or_else(|| UnityDeError::unexpected("identifier", self.peek_line()))?;
         self.get_str(pos)
     }
 
     fn next_char(&mut self) -> super::Result<char> {
-        let ret = self.chars().next().ok_or_else(|| UnityDeError::Eof)?;
-        self.skip(1)?;
+        let ret = self.data[self.offset..]
+            .chars()
+            .next()
+            .ok_or(UnityDeError::Eof { location: None })?;
+        self.skip(ret.len_utf8())?;
         Ok(ret)
     }
 
-    fn get_content(&mut self) -> super::Result<&str> {
-        let pos = self
-            .chars()
-            .position(|c| c == ' ' || c == '\r' || c == '\n')
-            .ok_or_else(|| UnityDeError::Eof)?;
+    fn get_content(&mut self) -> super::Result<&'de str> {
+        let pos = memchr3(b' ', b'\r', b'\n', self.bytes())
+            .ok_or(UnityDeError::Eof { location: None })?;
         self.get_str(pos)
     }
 
-    fn get_content_by<T: FromStr>(&mut self) -> super::Result<T> {
+    fn get_content_by<T: FromStr + Default>(&mut self) -> super::Result<T> {
         let content = self.get_content()?;
-        match T::from_str(content) {
-            Ok(t) => {
-                self.skip_line()?;
-                Ok(t)
+        let value = match T::from_str(content) {
+            Ok(t) => t,
+            Err(_) => {
+                // report the start of the value rather than what follows it
+                self.offset -= content.len();
+                self.recover(UnityDeError::ParseNumber {
+                    content: content.to_string(),
+                    location: None,
+                })?;
+                self.offset += content.len();
+                T::default()
             }
-            Err(_) => Err(UnityDeError::custom(format!("parse '{}' failed", content))),
-        }
+        };
+        self.skip_line()?;
+        Ok(value)
     }
 
     fn skip_array_header(&mut self) -> super::Result<()> {
-        let count = self.count_until(':');
+        let count = self.count_until(b':');
         self.skip(count + 1)
     }
 
-    fn peek_line(&self) -> &str {
-        let pos = self
-            .chars()
-            .position(|c| c == '\r' || c == '\n')
-            .unwrap_or(self.data.len() - self.offset);
-        self.peek_str(pos).unwrap_or("")
+    fn peek_line(&self) -> &'de str {
+        // the line end stays valid as long as we are still on the same line
+        let (bgn, end) = self.line.get();
+        let end = if bgn <= self.offset && self.offset <= end {
+            end
+        } else {
+            let end = self.offset
+                + memchr2(b'\r', b'\n', self.bytes()).unwrap_or_else(|| self.remaining());
+            self.line.set((self.offset, end));
+            end
+        };
+        &self.data[self.offset..end]
     }
 
     fn is_seq_multi(&self) -> super::Result<bool> {
-        Ok(self.regex.is_match(self.peek_line()))
+        Ok(is_array_header(self.peek_line().trim_start_matches('\t')))
+    }
+
+    fn is_packed_bytes(&mut self) -> super::Result<bool> {
+        Ok(self.is_seq_multi()? && matches!(self.peek_type()?, "UInt8" | "unsigned char"))
+    }
+
+    fn get_packed_bytes(&mut self, count: usize) -> super::Result<Vec<u8>> {
+        // every byte takes at least two characters, don't trust the size blindly
+        let mut bytes = Vec::with_capacity(count.min(self.remaining() / 2));
+        while bytes.len() < count {
+            //input='\t\tdata (UInt8) #25: 0 0 0 3 0 ...'
+            self.skip_array_header()?;
+            let row = self.peek_line().as_bytes();
+            let mut pos = 0;
+            while let Some((bgn, end)) = scan_bytes(&row[pos..], &mut bytes) {
+                let token = &row[pos + bgn..pos + end];
+                self.recover(UnityDeError::ParseNumber {
+                    content: String::from_utf8_lossy(token).into_owned(),
+                    location: Some(Location::of(self.data, self.offset + pos + bgn)),
+                })?;
+                bytes.push(0);
+                pos += end;
+            }
+            self.skip_until(b'\n')?;
+        }
+        if bytes.len() != count {
+            return Err(UnityDeError::TypeMismatch {
+                expected: format!("{} bytes", count),
+                found: format!("{} bytes", bytes.len()),
+                location: None,
+            });
+        }
+        Ok(bytes)
+    }
+
+    /// Reads the `size N (int)` line which follows the header of a vector or map.
+    fn get_size(&mut self) -> super::Result<usize> {
+        //current:\t+ size xxx (int)
+        trace!("get_size:input='{}'", self.peek_line());
+        self.skip_tab(self.tab_count())?;
+        let id = self.get_identifier()?;
+        if id != "size" {
+            return Err(UnityDeError::unexpected("size", id));
+        }
+        // 57 (int)
+        self.skip_space()?;
+        self.get_content_by()
     }
 
     fn is_empty(&self) -> bool {
         self.offset == self.data.len()
     }
+
+    fn deserialize_document<T: Deserialize<'de>>(&mut self) -> super::Result<T> {
+        self.skip_header()?;
+        let t = self.deserialize_root()?;
+        self.skip_line()?;
+        self.skip_line()?;
+        if !self.is_empty() {
+            self.recover(UnityDeError::unexpected("end of file", self.peek_line()))?;
+        }
+        Ok(t)
+    }
+
+    /// Deserializes the object whose `ID: N (ClassID: C) Type` line starts at the offset.
+    fn deserialize_root<T: Deserialize<'de>>(&mut self) -> super::Result<T> {
+        span!(DEBUG, "object", header = self.peek_line());
+        self.skip_until(b')')?;
+        T::deserialize(&mut *self)
+    }
 }
 
-pub fn from_str<'a, T: Deserialize<'a>>(data: &'a str) -> super::Result<T> {
+/// Appends the space separated numbers of a packed `UInt8` row to `bytes` in a single
+/// pass, stopping at the first token which isn't a number up to 255 to return its range.
+fn scan_bytes(row: &[u8], bytes: &mut Vec<u8>) -> Option<(usize, usize)> {
+    let mut value = 0u32;
+    let mut start = None;
+    for (i, c) in row.iter().enumerate() {
+        if *c == b' ' {
+            if start.take().is_some() {
+                bytes.push(value as u8);
+                value = 0;
+            }
+            continue;
+        }
+        let bgn = *start.get_or_insert(i);
+        let digit = c.wrapping_sub(b'0') as u32;
+        value = value * 10 + digit;
+        if digit > 9 || value > 255 {
+            let end = memchr(b' ', &row[i..]).map_or(row.len(), |len| i + len);
+            return Some((bgn, end));
+        }
+    }
+    if start.is_some() {
+        bytes.push(value as u8);
+    }
+    None
+}
+
+/// Matches the start of a packed row, `data (type) #index:`.
+fn is_array_header(line: &str) -> bool {
+    let rest = match line.strip_prefix("data (") {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let type_len = rest
+        .bytes()
+        .take_while(|b| b.is_ascii_alphanumeric() || *b == b' ')
+        .count();
+    let rest = match rest[type_len..].strip_prefix(") #") {
+        Some(rest) if type_len != 0 => rest,
+        _ => return false,
+    };
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+    digits != 0 && rest[digits..].starts_with(':')
+}
+
+/// Reads the file at `path` and deserializes it.
+pub fn from_path<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> super::Result<T> {
+    let path = path.as_ref();
+    span!(DEBUG, "from_path", asset = %path.display());
+    let data = std::fs::read_to_string(path)?;
+    from_str(&data)
+}
+
+/// Memory-maps the file at `path` and deserializes it, so large dumps are
+/// paged in by the OS while parsing instead of being read into memory first.
+///
+/// # Safety
+///
+/// The file must not be truncated or rewritten while it is parsed: reading pages
+/// past the new end of a mapped file raises `SIGBUS`, which kills the process. Use
+/// [`from_path`] for files another process may change, e.g. while Unity exports them.
+pub unsafe fn from_path_mmap<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> super::Result<T> {
+    let path = path.as_ref();
+    span!(DEBUG, "from_path_mmap", asset = %path.display());
+    let file = File::open(path)?;
+    let mmap = Mmap::map(&file)?;
+    let data = std::str::from_utf8(&mmap).map_err(UnityDeError::custom)?;
+    from_str(data)
+}
+
+/// Deserializes the object whose header line starts at `offset`, `data` ends with
+/// the blank lines after it. Errors are located within all of `data`.
+pub(crate) fn from_object<'a, T: Deserialize<'a>>(
+    data: &'a str,
+    offset: usize,
+) -> super::Result<T> {
     let mut de = UnityDeserializer::from_str(data);
-    de.skip_header()?;
-    de.skip_until(')')?;
-    let t = T::deserialize(&mut de)?;
-    de.skip_line()?;
-    de.skip_line()?;
-    if de.is_empty() {
-        Ok(t)
-    } else {
-        Err(UnityDeError::custom(format!(
-            "tailing data:'{}'",
-            de.peek_line()
-        )))
+    de.offset = offset;
+    let result = de.deserialize_root().and_then(|t| {
+        while !de.is_empty() && de.peek_line().trim().is_empty() {
+            de.skip_until(b'\n')?;
+        }
+        if de.is_empty() {
+            Ok(t)
+        } else {
+            Err(UnityDeError::unexpected("end of object", de.peek_line()))
+        }
+    });
+    result.map_err(|err| err.at(Location::of(data, de.offset)))
+}
+
+/// Deserializes like [`from_str`], but doesn't stop at the first error.
+///
+/// A value which doesn't parse is read as zero, a malformed field line is skipped
+/// together with everything nested below it, and missing fields without a serde
+/// default take their zero value, each recording an error. Other errors end parsing,
+/// the value is `None` then.
+pub fn parse_all_errors<'a, T: Deserialize<'a>>(data: &'a str) -> (Option<T>, Vec<UnityDeError>) {
+    span!(DEBUG, "parse_all_errors", bytes = data.len());
+    let mut synthesize: HashMap<_, Vec<_>> = HashMap::new();
+    loop {
+        let mut de = UnityDeserializer::from_str(data);
+        de.errors = Some(Vec::new());
+        de.synthesize = std::mem::take(&mut synthesize);
+        let result = de.deserialize_document();
+        let mut errors = de.errors.take().unwrap_or_default();
+        synthesize = std::mem::take(&mut de.synthesize);
+        match (result, de.missing.take()) {
+            (Ok(t), _) => return (Some(t), errors),
+            // serde only tells a field has no default by failing at the end of its
+            // struct. Parse again with the field read as zero in every struct of that
+            // type, so there is one more pass per type and field, not per occurrence.
+            (Err(_), Some((name, field)))
+                if !synthesize.get(name).is_some_and(|f| f.contains(&field)) =>
+            {
+                synthesize.entry(name).or_default().push(field)
+            }
+            (Err(err), _) => {
+                errors.push(err.at(Location::of(data, de.offset)));
+                return (None, errors);
+            }
+        }
     }
 }
 
-impl<'de, 'a> Deserializer<'de> for &'a mut UnityDeserializer<'de> {
+pub fn from_str<'a, T: Deserialize<'a>>(data: &'a str) -> super::Result<T> {
+    span!(DEBUG, "from_str", bytes = data.len());
+    let mut de = UnityDeserializer::from_str(data);
+    de.deserialize_document()
+        .map_err(|err| err.at(Location::of(data, de.offset)))
+}
+
+impl<'de> Deserializer<'de> for &mut UnityDeserializer<'de> {
     type Error = UnityDeError;
 
-    fn deserialize_any<V>(mut self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_any<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         match self.current_status() {
             DeStatus::StructKey => {
                 //name content type
-                log::trace!("deserialize_any:StructKey, input='{}'", self.peek_line());
+                trace!("deserialize_any:StructKey, input='{}'", self.peek_line());
                 self.deserialize_identifier(visitor)
             }
-            DeStatus::Invalid => unreachable!("invalid status"),
+            // the root object, there's no value line before it
+            DeStatus::Invalid => self.deserialize_struct("", &[], visitor),
             _ => {
                 //2. content type
                 self.type_name = if let DeStatus::MultipleElement = self.current_status() {
-                    self.type_name.as_str()
+                    self.type_name
                 } else {
                     self.peek_type()?
-                }
-                .into();
-                log::trace!(
+                };
+                trace!(
                     "deserialize_any:StructValue, type={}, input='{}'",
                     self.type_name,
                     self.peek_line()
                 );
-                match self.type_name.as_str() {
+                match self.type_name {
                     "vector" => self.deserialize_seq(visitor),
+                    "map" => self.deserialize_map(visitor),
                     "SInt64" => self.deserialize_i64(visitor),
                     "unsigned int" => self.deserialize_u32(visitor),
                     "int" => self.deserialize_i32(visitor),
@@ -352,11 +596,30 @@ impl<'de, 'a> Deserializer<'de> for &'a mut UnityDeserializer<'de> {
         visitor.visit_f64(self.get_content_by()?)
     }
 
-    fn deserialize_char<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_char<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_char")
+        //input='a (char)' or '"a" (string)'
+        let line = self.peek_line();
+        let mut quoted = line.char_indices().skip(1);
+        let (content, len) = match (line.starts_with('"'), quoted.next(), quoted.next()) {
+            // a bare '"' isn't followed by another quote
+            (true, Some((_, c)), Some((end, '"'))) => (&line[1..end], c.len_utf8() + 2),
+            _ => {
+                let end = line.find(' ').unwrap_or(line.len());
+                (&line[..end], end)
+            }
+        };
+        let mut chars = content.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => {
+                self.skip(len)?;
+                self.skip_line()?;
+                visitor.visit_char(c)
+            }
+            _ => Err(UnityDeError::unexpected("single character", content)),
+        }
     }
 
     fn deserialize_str<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
@@ -373,89 +636,112 @@ impl<'de, 'a> Deserializer<'de> for &'a mut UnityDeserializer<'de> {
     where
         V: Visitor<'de>,
     {
-        let content = self.get_content()?;
-        let len = content.len();
-        let content = content[1..len - 1].into();
+        //input='"content with spaces" (string)'
+        let line = self.peek_line();
+        let end = line
+            .rfind('"')
+            .filter(|end| *end > 0 && line.starts_with('"'))
+            .ok_or_else(|| UnityDeError::unexpected("quoted string", line))?;
+        let content = line[1..end].into();
         self.skip_line()?;
         visitor.visit_string(content)
     }
 
-    fn deserialize_bytes<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_bytes")
+        // the bytes are decoded from text, there is nothing to borrow
+        self.deserialize_byte_buf(visitor)
     }
 
-    fn deserialize_byte_buf<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_byte_buf")
+        //begin as ' (vector)' of UInt8, scanned a row at a time
+        trace!("deserialize_byte_buf:input='{}'", self.peek_line());
+        self.skip_line()?;
+        let count = self.get_size()?;
+        if count == 0 {
+            // the blank line after an empty vector
+            self.skip_line()?;
+            return visitor.visit_byte_buf(Vec::new());
+        }
+        if !self.is_packed_bytes()? {
+            return Err(UnityDeError::unexpected(
+                "data (UInt8) #0:",
+                self.peek_line().trim_start_matches('\t'),
+            ));
+        }
+        visitor.visit_byte_buf(self.get_packed_bytes(count)?)
     }
 
-    fn deserialize_option<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_option<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_option")
+        // a field which is present has a value, missing fields of an Option are None
+        visitor.visit_some(self)
     }
 
-    fn deserialize_unit<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_unit<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_unit")
+        //input=' (Type)', anything nested below it is skipped
+        trace!("deserialize_unit:input='{}'", self.peek_line());
+        self.skip_block(self.tab)?;
+        visitor.visit_unit()
     }
 
     fn deserialize_unit_struct<V>(
         self,
-        _name: &'static str,
-        _visitor: V,
+        name: &'static str,
+        visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_unit_struct")
+        let id = self.peek_type()?;
+        if name != id {
+            self.recover(UnityDeError::TypeMismatch {
+                expected: name.to_string(),
+                found: id.to_string(),
+                location: None,
+            })?;
+        }
+        self.deserialize_unit(visitor)
     }
 
     fn deserialize_newtype_struct<V>(
         self,
         _name: &'static str,
-        _visitor: V,
+        visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_newtype_struct")
+        // transparent, the dump holds the wrapped value
+        visitor.visit_newtype_struct(self)
     }
 
-    fn deserialize_seq<V>(mut self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_seq<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
         //begin as ' (vector)'
-        log::trace!("deserialize_seq:input='{}'", self.peek_line());
+        trace!("deserialize_seq:input='{}'", self.peek_line());
         self.skip_line()?;
 
-        let typ = format!("{}", &visitor as &dyn Expected);
-        let (count, faked) = if typ.as_str() == "Hash128" {
-            (16, true)
-        } else {
-            //current:\t+ size xxx (int)
-            log::trace!("deserialize_seq:input='{}'", self.peek_line());
-            self.skip_tab(self.tab_count())?;
-            if self.get_identifier()? != "size" {
-                return Err(UnityDeError::custom("no size found"));
-            }
-            // 57 (int)
-            log::trace!("deserialize_seq:input='{}'", self.peek_line());
-            self.skip_space()?;
-            (self.get_content_by()?, false)
-        };
+        let count = self.get_size()?;
+        if count != 0 && self.is_packed_bytes()? {
+            let bytes = self.get_packed_bytes(count)?;
+            return visitor.visit_seq(SeqDeserializer::new(bytes.into_iter()));
+        }
 
         self.tab += 1;
-        let access = UnitySeqAccess::new(&mut self, count, faked);
+        let access = UnitySeqAccess::new(self, count);
         let ret = visitor.visit_seq(access);
         self.tab -= 1;
         ret
@@ -463,36 +749,61 @@ impl<'de, 'a> Deserializer<'de> for &'a mut UnityDeserializer<'de> {
 
     fn deserialize_tuple<V>(
         self,
-        _len: usize,
-        _visitor: V,
+        len: usize,
+        visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_tuple")
+        //begin as ' (Type)', followed by one line per element like the fields of a struct
+        //input='\t\tbytes[0] 12 (UInt8)'
+        trace!("deserialize_tuple:input='{}'", self.peek_line());
+        self.skip_line()?;
+        self.tab += 1;
+        let access = UnityTupleAccess::new(self, len);
+        let ret = visitor.visit_seq(access);
+        self.tab -= 1;
+        ret
     }
 
     fn deserialize_tuple_struct<V>(
         self,
-        _name: &'static str,
-        _len: usize,
-        _visitor: V,
+        name: &'static str,
+        len: usize,
+        visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_tuple_struct")
+        let id = self.peek_type()?;
+        if name != id {
+            self.recover(UnityDeError::TypeMismatch {
+                expected: name.to_string(),
+                found: id.to_string(),
+                location: None,
+            })?;
+        }
+        self.deserialize_tuple(len, visitor)
     }
 
-    fn deserialize_map<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
+    fn deserialize_map<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_map")
+        //begin as ' (map)'
+        trace!("deserialize_map:input='{}'", self.peek_line());
+        self.skip_line()?;
+        let count = self.get_size()?;
+
+        self.tab += 1;
+        let access = UnityPairAccess::new(self, count);
+        let ret = visitor.visit_map(access);
+        self.tab -= 1;
+        ret
     }
 
     fn deserialize_struct<V>(
-        mut self,
+        self,
         name: &'static str,
         _fields: &'static [&'static str],
         visitor: V,
@@ -504,8 +815,7 @@ impl<'de, 'a> Deserializer<'de> for &'a mut UnityDeserializer<'de> {
         //1. TypeName
         //2. (TypeName)
         //3. data (TypeName)
-        log::trace!("deserialize_struct:input='{}'", self.peek_line());
-        let tab = self.tab;
+        trace!("deserialize_struct:input='{}'", self.peek_line());
         self.skip_space()?;
         let id = if self.root {
             self.root = false;
@@ -513,18 +823,23 @@ impl<'de, 'a> Deserializer<'de> for &'a mut UnityDeserializer<'de> {
         } else {
             self.peek_type()?
         };
-        if name != "" && name != id {
-            return Err(UnityDeError::custom(format!(
-                "type {} not match {}",
-                name, id
-            )));
+        if !name.is_empty() && name != id {
+            self.recover(UnityDeError::TypeMismatch {
+                expected: name.to_string(),
+                found: id.to_string(),
+                location: None,
+            })?;
         }
-        log::trace!("deserialize_struct: id={}, tab = {}", id, tab + 1);
+        trace!("deserialize_struct: id={}, tab = {}", id, self.tab + 1);
         self.skip_line()?;
         self.tab += 1;
-        let access = UnityMapAccess::new(&mut self);
-        let ret = visitor.visit_map(access);
+        let ret = visitor.visit_map(UnityMapAccess::new(self, name));
         self.tab -= 1;
+        if let Err(UnityDeError::MissingField { field, .. }) = &ret {
+            if self.errors.is_some() && self.missing.is_none() {
+                self.missing = Some((name, *field));
+            }
+        }
         ret
     }
 
@@ -532,12 +847,32 @@ impl<'de, 'a> Deserializer<'de> for &'a mut UnityDeserializer<'de> {
         self,
         _name: &'static str,
         _variants: &'static [&'static str],
-        _visitor: V,
+        visitor: V,
     ) -> Result<<V as Visitor<'de>>::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
-        unimplemented!("deserialize_enum")
+        //input='1 (int)', the index of the variant
+        //input='"Name" (string)' or 'Name (type)'
+        trace!("deserialize_enum:input='{}'", self.peek_line());
+        let line = self.peek_line();
+        let variant = match line.strip_prefix('"') {
+            Some(rest) => {
+                // packed rows hold several names on one line
+                let end = rest
+                    .find('"')
+                    .ok_or_else(|| UnityDeError::unexpected("quoted string", line))?;
+                self.skip(end + 2)?;
+                &rest[..end]
+            }
+            None => self.get_content()?,
+        };
+        let ret = match variant.parse::<u32>() {
+            Ok(index) => visitor.visit_enum(U32Deserializer::new(index)),
+            Err(_) => visitor.visit_enum(BorrowedStrDeserializer::new(variant)),
+        };
+        self.skip_line()?;
+        ret
     }
 
     fn deserialize_identifier<V>(
@@ -548,7 +883,7 @@ impl<'de, 'a> Deserializer<'de> for &'a mut UnityDeserializer<'de> {
         V: Visitor<'de>,
     {
         //input='identifier data (type)'
-        log::trace!("deserialize_identifier:input='{}'", self.peek_line());
+        trace!("deserialize_identifier:input='{}'", self.peek_line());
         let id = self.get_identifier()?;
         visitor.visit_str(id)
     }
@@ -560,19 +895,38 @@ impl<'de, 'a> Deserializer<'de> for &'a mut UnityDeserializer<'de> {
     where
         V: Visitor<'de>,
     {
-        log::trace!("deserialize_ignored_any:input='{}'", self.peek_line());
-        self.deserialize_any(visitor)
+        trace!("deserialize_ignored_any:input='{}'", self.peek_line());
+        match self.current_status() {
+            DeStatus::MultipleElement => {
+                self.get_content()?;
+            }
+            DeStatus::StructKey => {
+                self.get_identifier()?;
+            }
+            _ => self.skip_block(self.tab)?,
+        }
+        visitor.visit_unit()
     }
 }
 
 struct UnityMapAccess<'a, 'de: 'a> {
     tab: usize,
     de: &'a mut UnityDeserializer<'de>,
+    /// fields to read as zero unless they show up, only set by `parse_all_errors`
+    synthesize: Vec<&'static str>,
+    /// the field of the next value, which is missing from the input
+    missing_value: Option<&'static str>,
 }
 
 impl<'a, 'de> UnityMapAccess<'a, 'de> {
-    fn new(de: &'a mut UnityDeserializer<'de>) -> Self {
-        UnityMapAccess { tab: de.tab, de }
+    fn new(de: &'a mut UnityDeserializer<'de>, name: &'static str) -> Self {
+        let synthesize = de.synthesize.get(name).cloned().unwrap_or_default();
+        UnityMapAccess {
+            tab: de.tab,
+            de,
+            synthesize,
+            missing_value: None,
+        }
     }
 }
 
@@ -588,13 +942,23 @@ impl<'a, 'de> MapAccess<'de> for UnityMapAccess<'a, 'de> {
     {
         let tab = self.de.tab_count();
         //input='\t\tName data (type)'
-        log::trace!("next_key_seed:input='{}'", self.de.peek_line());
-        if tab < self.tab {
-            log::trace!("-----end struct:{}----", self.tab);
+        trace!("next_key_seed:input='{}'", self.de.peek_line());
+        if tab < self.tab || self.de.is_empty() {
+            if let Some(field) = self.synthesize.pop() {
+                self.missing_value = Some(field);
+                return seed
+                    .deserialize(BorrowedStrDeserializer::new(field))
+                    .map(Some);
+            }
+            trace!("-----end struct:{}----", self.tab);
             return Ok(None);
         }
 
         self.de.skip_tab(tab)?;
+        if !self.synthesize.is_empty() {
+            let key = self.de.peek_line().split(' ').next().unwrap_or_default();
+            self.synthesize.retain(|field| *field != key);
+        }
         self.de.status.push(DeStatus::StructKey);
         let ret = seed.deserialize(&mut *self.de).map(Some);
         self.de.status.pop();
@@ -608,39 +972,184 @@ impl<'a, 'de> MapAccess<'de> for UnityMapAccess<'a, 'de> {
     where
         V: DeserializeSeed<'de>,
     {
+        if let Some(field) = self.missing_value.take() {
+            self.de.recover(UnityDeError::MissingField {
+                field,
+                location: None,
+            })?;
+            return seed.deserialize(DefaultDeserializer);
+        }
         //input=' data (type)'
+        if self.de.errors.is_some() {
+            let line = self.de.peek_line();
+            if !line.starts_with(' ') || self.de.peek_type().is_err() {
+                self.de
+                    .recover(UnityDeError::unexpected("' value (type)'", line))?;
+                self.de.skip_block(self.tab)?;
+                return seed.deserialize(DefaultDeserializer);
+            }
+        }
+        if self.de.next_char()? != ' ' {
+            return Err(UnityDeError::unexpected("space", self.de.peek_line()));
+        }
+        trace!("next_value_seed:input='{}'", self.de.peek_line());
+        self.de.status.push(DeStatus::StructValue);
+        let ret = seed.deserialize(&mut *self.de);
+        self.de.status.pop();
+        ret
+    }
+}
+
+/// Entries of a `(map)`, each written as a `data  (pair)` with `first` and `second` below it.
+struct UnityPairAccess<'a, 'de: 'a> {
+    tab: usize,
+    de: &'a mut UnityDeserializer<'de>,
+    remaining: usize,
+}
+
+impl<'a, 'de> UnityPairAccess<'a, 'de> {
+    fn new(de: &'a mut UnityDeserializer<'de>, count: usize) -> Self {
+        UnityPairAccess {
+            tab: de.tab,
+            de,
+            remaining: count,
+        }
+    }
+
+    fn next_member<T: DeserializeSeed<'de>>(
+        &mut self,
+        name: &'static str,
+        seed: T,
+    ) -> super::Result<T::Value> {
+        //input='\t\t\tfirst data (type)'
+        self.de.skip_tab(self.tab + 1)?;
+        let id = self.de.get_identifier()?;
+        if id != name {
+            return Err(UnityDeError::unexpected(name, id));
+        }
         if self.de.next_char()? != ' ' {
-            return Err(UnityDeError::custom(format!(
-                "invalid line:{}",
-                self.de.peek_line()
-            )));
+            return Err(UnityDeError::unexpected("space", self.de.peek_line()));
         }
-        log::trace!("next_value_seed:input='{}'", self.de.peek_line());
+        self.de.tab += 1;
         self.de.status.push(DeStatus::StructValue);
         let ret = seed.deserialize(&mut *self.de);
         self.de.status.pop();
+        self.de.tab -= 1;
         ret
     }
 }
 
+impl<'a, 'de> MapAccess<'de> for UnityPairAccess<'a, 'de> {
+    type Error = UnityDeError;
+
+    fn next_key_seed<K>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<<K as DeserializeSeed<'de>>::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        if self.remaining == 0 {
+            trace!("map end");
+            self.de.skip_line()?;
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        //input='\t\tdata  (pair)'
+        self.de.skip_tab(self.tab)?;
+        let id = self.de.get_identifier()?;
+        if id != "data" {
+            return Err(UnityDeError::unexpected("data", id));
+        }
+        self.de.skip_line()?;
+        self.next_member("first", seed).map(Some)
+    }
+
+    fn next_value_seed<V>(
+        &mut self,
+        seed: V,
+    ) -> Result<<V as DeserializeSeed<'de>>::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        // a `None` value is written without its `second` line
+        let line = self.de.peek_line();
+        let tab = self.tab + 1;
+        if self.de.tab_count() != tab || !line[tab..].starts_with("second ") {
+            return seed.deserialize(().into_deserializer());
+        }
+        self.next_member("second", seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// Elements of a tuple or fixed size array, each on a line of its own whatever its name.
+struct UnityTupleAccess<'a, 'de: 'a> {
+    tab: usize,
+    de: &'a mut UnityDeserializer<'de>,
+    remaining: usize,
+}
+
+impl<'a, 'de> UnityTupleAccess<'a, 'de> {
+    fn new(de: &'a mut UnityDeserializer<'de>, len: usize) -> Self {
+        UnityTupleAccess {
+            tab: de.tab,
+            de,
+            remaining: len,
+        }
+    }
+}
+
+impl<'a, 'de> SeqAccess<'de> for UnityTupleAccess<'a, 'de> {
+    type Error = UnityDeError;
+
+    fn next_element_seed<T>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<<T as DeserializeSeed<'de>>::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        //input='\t\tname data (type)'
+        self.de.skip_tab(self.tab)?;
+        self.de.get_identifier()?;
+        if self.de.next_char()? != ' ' {
+            return Err(UnityDeError::unexpected("space", self.de.peek_line()));
+        }
+        self.de.status.push(DeStatus::StructValue);
+        let ret = seed.deserialize(&mut *self.de).map(Some);
+        self.de.status.pop();
+        ret
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
 struct UnitySeqAccess<'a, 'de: 'a> {
     tab: usize,
     de: &'a mut UnityDeserializer<'de>,
     current: usize,
     count: usize,
     multiple: bool,
-    faked: bool,
 }
 
 impl<'a, 'de> UnitySeqAccess<'a, 'de> {
-    fn new(de: &'a mut UnityDeserializer<'de>, count: usize, faked: bool) -> Self {
+    fn new(de: &'a mut UnityDeserializer<'de>, count: usize) -> Self {
         UnitySeqAccess {
             tab: de.tab,
             current: 0,
             multiple: false,
             de,
             count,
-            faked,
         }
     }
 }
@@ -663,17 +1172,17 @@ impl<'a, 'de> SeqAccess<'de> for UnitySeqAccess<'a, 'de> {
         //input='\t\tdata (data,data) (type)...'
         if self.current == 0 && self.count != 0 {
             if self.de.is_seq_multi()? {
-                self.de.type_name = self.de.peek_type()?.into();
+                self.de.type_name = self.de.peek_type()?;
                 self.multiple = true;
                 self.de.status.push(DeStatus::MultipleElement);
             } else {
                 self.multiple = false;
                 self.de.status.push(DeStatus::SingleElement);
             }
-            //log::trace!("next_element_seed:input='{}'", self.de.get_line());
+            //trace!("next_element_seed:input='{}'", self.de.get_line());
         }
         if self.current == self.count {
-            log::trace!("seq end at {}", self.current);
+            trace!("seq end at {}", self.current);
             if self.count != 0 {
                 self.de.status.pop();
             }
@@ -682,21 +1191,20 @@ impl<'a, 'de> SeqAccess<'de> for UnitySeqAccess<'a, 'de> {
         }
 
         if self.multiple {
-            if self.current % ArrayMemberColumns == 0 {
+            if self.current.is_multiple_of(ArrayMemberColumns) {
                 self.de.skip_array_header()?;
             }
             self.de.skip_space()?;
         } else {
             self.de.skip_tab(self.tab)?;
-            if self.de.get_identifier()? != "data" && !self.faked {
-                return Err(UnityDeError::custom(
-                    format! {"no data keyword found in seq:{}", self.de.peek_line()},
-                ));
+            let id = self.de.get_identifier()?;
+            if id != "data" {
+                return Err(UnityDeError::unexpected("data", id));
             }
             self.de.skip_space()?;
         }
         self.current += 1;
-        log::trace!("next_element_seed:input='{}'", self.de.peek_line());
+        trace!("next_element_seed:input='{}'", self.de.peek_line());
         seed.deserialize(&mut *self.de).map(Some)
     }
 }
@@ -0,0 +1,91 @@
+use serde::Deserialize;
+
+use super::{Location, Result, UnityDeError};
+
+/// One object of a Unity text dump, from its `ID: N (ClassID: C) Type` line up to
+/// the next object.
+#[derive(Debug, Clone, Copy)]
+pub struct UnityObject<'a> {
+    pub id: i64,
+    pub class_id: i32,
+    pub type_name: &'a str,
+    /// the dump up to the end of this object, so errors keep their line in the file
+    data: &'a str,
+    offset: usize,
+}
+
+impl<'a> UnityObject<'a> {
+    /// The text of the object, starting with its header line.
+    pub fn text(&self) -> &'a str {
+        &self.data[self.offset..]
+    }
+
+    /// Deserializes the object, the serde name of `T` must be the object's type name.
+    pub fn deserialize<T: Deserialize<'a>>(&self) -> Result<T> {
+        super::deserializer::from_object(self.data, self.offset)
+    }
+}
+
+/// Iterates over the objects of a dump holding any number of them, such as a scene.
+///
+/// Only the header lines are parsed while iterating, each object is deserialized on
+/// demand with [`UnityObject::deserialize`].
+pub fn objects(data: &str) -> Objects<'_> {
+    Objects {
+        data,
+        next: find_header(data, 0),
+    }
+}
+
+/// Iterator returned by [`objects`].
+#[derive(Debug, Clone)]
+pub struct Objects<'a> {
+    data: &'a str,
+    next: Option<usize>,
+}
+
+impl<'a> Iterator for Objects<'a> {
+    type Item = Result<UnityObject<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.next?;
+        let line_end = memchr::memchr(b'\n', &self.data.as_bytes()[offset..])
+            .map_or(self.data.len(), |pos| offset + pos);
+        self.next = find_header(self.data, line_end);
+        let end = self.next.unwrap_or(self.data.len());
+        let header = self.data[offset..line_end].trim_end();
+        Some(match parse_header(header) {
+            Some((id, class_id, type_name)) => Ok(UnityObject {
+                id,
+                class_id,
+                type_name,
+                data: &self.data[..end],
+                offset,
+            }),
+            None => Err(UnityDeError::UnexpectedToken {
+                expected: "ID: N (ClassID: C) Type",
+                found: header.to_string(),
+                location: Some(Location::of(self.data, offset)),
+            }),
+        })
+    }
+}
+
+/// Offset of the first object header line starting at or after `from`, fields are
+/// indented so any line starting with `ID: ` is one.
+fn find_header(data: &str, from: usize) -> Option<usize> {
+    if from == 0 && data.starts_with("ID: ") {
+        return Some(0);
+    }
+    memchr::memmem::find(&data.as_bytes()[from..], b"\nID: ").map(|pos| from + pos + 1)
+}
+
+fn parse_header(line: &str) -> Option<(i64, i32, &str)> {
+    let rest = line.strip_prefix("ID: ")?;
+    let (id, rest) = rest.split_once(" (ClassID: ")?;
+    let (class_id, type_name) = rest.split_once(") ")?;
+    if type_name.is_empty() {
+        return None;
+    }
+    Some((id.parse().ok()?, class_id.parse().ok()?, type_name))
+}
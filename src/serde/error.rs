@@ -0,0 +1,185 @@
+use std::fmt::{Display, Formatter};
+
+/// Position in the input where parsing stopped, both 1-based, the column counts bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub(crate) fn of(data: &str, offset: usize) -> Location {
+        let before = &data.as_bytes()[..offset];
+        let line_start = memchr::memrchr(b'\n', before).map_or(0, |pos| pos + 1);
+        Location {
+            line: memchr::memchr_iter(b'\n', before).count() + 1,
+            column: offset - line_start + 1,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {} column {}", self.line, self.column)
+    }
+}
+
+/// Errors of parsing and writing Unity text dumps.
+///
+/// Errors raised by [`crate::serde::from_str`] carry the [`Location`] parsing stopped at.
+#[derive(Debug)]
+pub enum UnityDeError {
+    /// the input doesn't have the expected shape, e.g. a line without a `(type)`
+    UnexpectedToken {
+        expected: &'static str,
+        found: String,
+        location: Option<Location>,
+    },
+    /// the dump holds another type than the one being deserialized
+    TypeMismatch {
+        expected: String,
+        found: String,
+        location: Option<Location>,
+    },
+    /// a value which doesn't parse as the number (or bool) it is declared as
+    ParseNumber {
+        content: String,
+        location: Option<Location>,
+    },
+    MissingField {
+        field: &'static str,
+        location: Option<Location>,
+    },
+    /// a line is indented at another depth than its parent expects
+    TabMismatch {
+        expected: usize,
+        found: usize,
+        location: Option<Location>,
+    },
+    /// any other error, e.g. from `serde::de::Error::custom`
+    Custom {
+        message: String,
+        location: Option<Location>,
+    },
+    /// the input ends in the middle of a value
+    Eof {
+        location: Option<Location>,
+    },
+    Io(std::io::Error),
+}
+
+impl UnityDeError {
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            UnityDeError::UnexpectedToken { location, .. }
+            | UnityDeError::TypeMismatch { location, .. }
+            | UnityDeError::ParseNumber { location, .. }
+            | UnityDeError::MissingField { location, .. }
+            | UnityDeError::TabMismatch { location, .. }
+            | UnityDeError::Custom { location, .. }
+            | UnityDeError::Eof { location } => *location,
+            UnityDeError::Io(_) => None,
+        }
+    }
+
+    /// Sets the location unless the error already has one.
+    pub(crate) fn at(mut self, at: Location) -> Self {
+        match &mut self {
+            UnityDeError::UnexpectedToken { location, .. }
+            | UnityDeError::TypeMismatch { location, .. }
+            | UnityDeError::ParseNumber { location, .. }
+            | UnityDeError::MissingField { location, .. }
+            | UnityDeError::TabMismatch { location, .. }
+            | UnityDeError::Custom { location, .. }
+            | UnityDeError::Eof { location } => {
+                location.get_or_insert(at);
+            }
+            UnityDeError::Io(_) => {}
+        }
+        self
+    }
+
+    pub(crate) fn unexpected(expected: &'static str, found: &str) -> Self {
+        UnityDeError::UnexpectedToken {
+            expected,
+            found: found.to_string(),
+            location: None,
+        }
+    }
+}
+
+impl serde::de::Error for UnityDeError {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        UnityDeError::Custom {
+            message: msg.to_string(),
+            location: None,
+        }
+    }
+
+    fn invalid_type(unexp: serde::de::Unexpected, exp: &dyn serde::de::Expected) -> Self {
+        UnityDeError::TypeMismatch {
+            expected: exp.to_string(),
+            found: unexp.to_string(),
+            location: None,
+        }
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        UnityDeError::MissingField {
+            field,
+            location: None,
+        }
+    }
+}
+
+impl serde::ser::Error for UnityDeError {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        <UnityDeError as serde::de::Error>::custom(msg)
+    }
+}
+
+impl std::error::Error for UnityDeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            UnityDeError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for UnityDeError {
+    fn from(err: std::io::Error) -> Self {
+        UnityDeError::Io(err)
+    }
+}
+
+impl Display for UnityDeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnityDeError::UnexpectedToken {
+                expected, found, ..
+            } => write!(f, "expected {}, found '{}'", expected, found)?,
+            UnityDeError::TypeMismatch {
+                expected, found, ..
+            } => write!(f, "type mismatch, expected {}, found {}", expected, found)?,
+            UnityDeError::ParseNumber { content, .. } => write!(f, "parse '{}' failed", content)?,
+            UnityDeError::MissingField { field, .. } => write!(f, "missing field {}", field)?,
+            UnityDeError::TabMismatch {
+                expected, found, ..
+            } => write!(f, "expected {} tabs, found {}", expected, found)?,
+            UnityDeError::Custom { message, .. } => f.write_str(message)?,
+            UnityDeError::Eof { .. } => f.write_str("end of file")?,
+            UnityDeError::Io(err) => write!(f, "io error:{}", err)?,
+        }
+        match self.location() {
+            Some(location) => write!(f, " at {}", location),
+            None => Ok(()),
+        }
+    }
+}
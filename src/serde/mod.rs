@@ -1,47 +1,150 @@
 use serde::de::{Error, SeqAccess, Visitor};
-use serde::export::fmt::Display;
-use serde::export::Formatter;
-use serde::{Deserialize, Deserializer};
+use serde::ser::SerializeTupleStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
+pub use deserializer::from_path;
+pub use deserializer::from_path_mmap;
 pub use deserializer::from_str;
+pub use deserializer::parse_all_errors;
 pub use deserializer::UnityDeserializer;
+pub use document::{objects, Objects, UnityObject};
+pub use error::{Location, UnityDeError};
+pub use registry::{LoadedObject, UnityClassRegistry};
+pub use serializer::to_string;
+pub use serializer::to_string_with_header;
+pub use serializer::UnitySerializer;
 
+mod defaults;
 mod deserializer;
+mod document;
+mod error;
+mod registry;
+mod serializer;
 
-#[derive(Debug)]
-pub enum UnityDeError {
-    Other(String),
-    Eof,
-}
+pub type Result<T> = std::result::Result<T, UnityDeError>;
 
-impl Error for UnityDeError {
-    fn custom<T>(msg: T) -> Self
-    where
-        T: Display,
-    {
-        let msg = format!("{}", msg);
-        UnityDeError::Other(msg)
+/// Writes a `float` the way Unity does with `%g`: positional from `1e-4` up to `1e6`,
+/// otherwise with an exponent of at least two digits such as `1.11022e-16`. Unlike
+/// `%g` it keeps the shortest digits which read back as the same value, which are
+/// Unity's own six for the values of a dump.
+pub(crate) struct UnityFloat(pub(crate) f32);
+
+impl Display for UnityFloat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let v = self.0;
+        let abs = v.abs();
+        // far enough from 1e-4 for the shortest digits to fall on the same side
+        if !v.is_finite() || v == 0.0 || (1e-3..1e6).contains(&abs) {
+            return write!(f, "{}", v);
+        }
+        let scientific = format!("{:e}", v);
+        let (mantissa, exponent) = scientific.split_once('e').unwrap();
+        let exponent: i32 = exponent.parse().unwrap();
+        if (-4..6).contains(&exponent) {
+            write!(f, "{}", v)
+        } else {
+            let sign = if exponent < 0 { '-' } else { '+' };
+            write!(f, "{}e{}{:02}", mantissa, sign, exponent.abs())
+        }
     }
 }
 
-impl std::error::Error for UnityDeError {}
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Vector3f {
+    pub(crate) x: f32,
+    pub(crate) y: f32,
+    pub(crate) z: f32,
+}
+
+impl Vector3f {
+    pub fn new(x: f32, y: f32, z: f32) -> Vector3f {
+        Vector3f { x, y, z }
+    }
+
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    pub fn y(&self) -> f32 {
+        self.y
+    }
 
-impl std::fmt::Display for UnityDeError {
+    pub fn z(&self) -> f32 {
+        self.z
+    }
+
+    pub fn to_array(&self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+/// Unity's `(x y z)` form.
+impl Display for Vector3f {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            UnityDeError::Other(msg) => f.write_str(msg),
-            UnityDeError::Eof => f.write_str("end of file"),
+        write!(
+            f,
+            "({} {} {})",
+            UnityFloat(self.x),
+            UnityFloat(self.y),
+            UnityFloat(self.z)
+        )
+    }
+}
+
+/// Parses `(x y z)`, the parentheses are optional.
+impl FromStr for Vector3f {
+    type Err = UnityDeError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        let inner = trimmed
+            .strip_prefix('(')
+            .and_then(|t| t.strip_suffix(')'))
+            .unwrap_or(trimmed);
+        let mut components = inner.split_ascii_whitespace().map(|c| {
+            c.parse().map_err(|_| UnityDeError::ParseNumber {
+                content: c.to_string(),
+                location: None,
+            })
+        });
+        let mut next = || {
+            components
+                .next()
+                .unwrap_or_else(|| Err(UnityDeError::unexpected("(x y z)", s)))
+        };
+        let v = Vector3f::new(next()?, next()?, next()?);
+        if components.next().is_some() {
+            return Err(UnityDeError::unexpected("(x y z)", s));
         }
+        Ok(v)
     }
 }
 
-pub type Result<T> = std::result::Result<T, UnityDeError>;
+impl From<[f32; 3]> for Vector3f {
+    fn from([x, y, z]: [f32; 3]) -> Self {
+        Vector3f { x, y, z }
+    }
+}
 
-#[derive(Debug)]
-pub struct Vector3f {
-    x: f32,
-    y: f32,
-    z: f32,
+impl From<Vector3f> for [f32; 3] {
+    fn from(v: Vector3f) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<(f32, f32, f32)> for Vector3f {
+    fn from((x, y, z): (f32, f32, f32)) -> Self {
+        Vector3f { x, y, z }
+    }
+}
+
+impl From<Vector3f> for (f32, f32, f32) {
+    fn from(v: Vector3f) -> Self {
+        (v.x, v.y, v.z)
+    }
 }
 
 struct Vector3fVisitor;
@@ -57,38 +160,32 @@ impl<'de> Visitor<'de> for Vector3fVisitor {
     where
         E: Error,
     {
+        //input='(x y z) (Vector3f)'
         let bgn = v
-            .chars()
-            .position(|c| c == '(')
+            .find('(')
             .ok_or_else(|| Error::custom(format!("no data found in {}", v)))?;
+        let end = v[bgn..]
+            .find(')')
+            .ok_or_else(|| Error::custom(format!("no data found in {}", &v[bgn..])))?;
+        v[bgn..=bgn + end].parse().map_err(Error::custom)
+    }
 
-        let end = v[bgn + 1..]
-            .chars()
-            .position(|c| c == ')')
-            .ok_or_else(|| Error::custom(format!("no data found in {}", &v[bgn + 1..])))?;
-
-        let mut content = v[bgn + 1..bgn + end + 1].split_ascii_whitespace();
-
-        let mut ret = Vector3f {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
+    fn visit_seq<A>(
+        self,
+        mut seq: A,
+    ) -> std::result::Result<Self::Value, <A as SeqAccess<'de>>::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut next = |i| {
+            seq.next_element()?
+                .ok_or_else(|| serde::de::Error::custom(format!("Vector3f missing {}th value", i)))
         };
-        for i in 0..3 {
-            let d = content
-                .next()
-                .ok_or_else(|| Error::custom(format!("no data{} found in {}", i, v)))?
-                .parse()
-                .map_err(|e| Error::custom(format!("{}", e)))?;
-            match i {
-                0 => ret.x = d,
-                1 => ret.y = d,
-                2 => ret.z = d,
-                _ => unreachable!(),
-            }
-        }
-
-        Ok(ret)
+        Ok(Vector3f {
+            x: next(0)?,
+            y: next(1)?,
+            z: next(2)?,
+        })
     }
 }
 
@@ -97,38 +194,85 @@ impl<'de> Deserialize<'de> for Vector3f {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(Vector3fVisitor)
+        if deserializer.is_human_readable() {
+            // "(x y z)" in Unity dumps, [x, y, z] in other text formats
+            deserializer.deserialize_any(Vector3fVisitor)
+        } else {
+            deserializer.deserialize_tuple(3, Vector3fVisitor)
+        }
     }
 }
 
-#[derive(Debug)]
+impl Serialize for Vector3f {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // named so the Unity serializer can write it inline as "(x y z)"
+        let mut tuple = serializer.serialize_tuple_struct("Vector3f", 3)?;
+        tuple.serialize_field(&self.x)?;
+        tuple.serialize_field(&self.y)?;
+        tuple.serialize_field(&self.z)?;
+        tuple.end()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Hash128 {
     bytes: [u8; 16],
 }
 
-struct Hash128Visitor;
+impl Hash128 {
+    pub fn new(bytes: [u8; 16]) -> Hash128 {
+        Hash128 { bytes }
+    }
 
-impl<'de> Visitor<'de> for Hash128Visitor {
-    type Value = Hash128;
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.bytes
+    }
+}
 
-    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        formatter.write_str("Hash128")
+/// The 32 hex digits Unity shows for a hash, one byte after another.
+impl Display for Hash128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for b in &self.bytes {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
     }
+}
 
-    fn visit_seq<A>(
-        self,
-        mut seq: A,
-    ) -> std::result::Result<Self::Value, <A as SeqAccess<'de>>::Error>
-    where
-        A: SeqAccess<'de>,
-    {
-        let mut hash = Hash128 { bytes: [0u8; 16] };
-        for i in 0..16 {
-            hash.bytes[i] = seq
-                .next_element()?
-                .ok_or_else(|| serde::de::Error::custom(format!("Hash128 missing {}th byte", i)))?;
+impl FromStr for Hash128 {
+    type Err = UnityDeError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s.len() != 32 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(UnityDeError::unexpected("32 hex digits", s));
+        }
+        let mut bytes = [0u8; 16];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
         }
-        Ok(hash)
+        Ok(Hash128 { bytes })
+    }
+}
+
+impl From<[u8; 16]> for Hash128 {
+    fn from(bytes: [u8; 16]) -> Self {
+        Hash128 { bytes }
+    }
+}
+
+impl From<Hash128> for [u8; 16] {
+    fn from(hash: Hash128) -> Self {
+        hash.bytes
+    }
+}
+
+impl AsRef<[u8]> for Hash128 {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
     }
 }
 
@@ -137,6 +281,123 @@ impl<'de> Deserialize<'de> for Hash128 {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_seq(Hash128Visitor)
+        // a fixed size array, the 16 `bytes[i]` lines in Unity dumps
+        Ok(Hash128 {
+            bytes: <[u8; 16]>::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for Hash128 {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple_struct("Hash128", 16)?;
+        for b in &self.bytes {
+            tuple.serialize_field(b)?;
+        }
+        tuple.end()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Quaternionf {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quaternionf {
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Quaternionf {
+        Quaternionf { x, y, z, w }
+    }
+
+    pub fn identity() -> Quaternionf {
+        Quaternionf::new(0.0, 0.0, 0.0, 1.0)
+    }
+}
+
+/// `(x y z w)`, in the style of Vector3f.
+impl Display for Quaternionf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({} {} {} {})",
+            UnityFloat(self.x),
+            UnityFloat(self.y),
+            UnityFloat(self.z),
+            UnityFloat(self.w)
+        )
+    }
+}
+
+impl Default for Quaternionf {
+    /// The identity rotation, as used by Unity for unrotated objects.
+    fn default() -> Self {
+        Quaternionf::identity()
+    }
+}
+
+impl From<[f32; 4]> for Quaternionf {
+    fn from([x, y, z, w]: [f32; 4]) -> Self {
+        Quaternionf { x, y, z, w }
+    }
+}
+
+impl From<Quaternionf> for [f32; 4] {
+    fn from(q: Quaternionf) -> Self {
+        [q.x, q.y, q.z, q.w]
+    }
+}
+
+#[allow(non_snake_case, clippy::upper_case_acronyms)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AABB {
+    pub m_Center: Vector3f,
+    pub m_Extent: Vector3f,
+}
+
+impl AABB {
+    pub fn new(center: Vector3f, extent: Vector3f) -> AABB {
+        AABB {
+            m_Center: center,
+            m_Extent: extent,
+        }
+    }
+
+    /// The box spanning `min` to `max`.
+    pub fn from_min_max(min: Vector3f, max: Vector3f) -> AABB {
+        AABB::new(
+            Vector3f::new(
+                (min.x + max.x) * 0.5,
+                (min.y + max.y) * 0.5,
+                (min.z + max.z) * 0.5,
+            ),
+            Vector3f::new(
+                (max.x - min.x) * 0.5,
+                (max.y - min.y) * 0.5,
+                (max.z - min.z) * 0.5,
+            ),
+        )
+    }
+
+    pub fn min(&self) -> Vector3f {
+        Vector3f {
+            x: self.m_Center.x - self.m_Extent.x,
+            y: self.m_Center.y - self.m_Extent.y,
+            z: self.m_Center.z - self.m_Extent.z,
+        }
+    }
+
+    pub fn max(&self) -> Vector3f {
+        Vector3f {
+            x: self.m_Center.x + self.m_Extent.x,
+            y: self.m_Center.y + self.m_Extent.y,
+            z: self.m_Center.z + self.m_Extent.z,
+        }
     }
 }
@@ -0,0 +1,128 @@
+use std::any::Any;
+
+use serde::de::DeserializeOwned;
+
+use super::{Result, UnityObject};
+use crate::navmesh::{NavMeshData, NavMeshProjectSettings};
+
+type Parser = fn(&UnityObject<'_>) -> Result<Box<dyn Any + Send>>;
+
+fn parse<T: DeserializeOwned + Send + 'static>(
+    object: &UnityObject<'_>,
+) -> Result<Box<dyn Any + Send>> {
+    Ok(Box::new(object.deserialize::<T>()?))
+}
+
+/// The Rust type each Unity class is loaded as, keyed by class id and type name.
+///
+/// Games register their own serialized components next to the built-in classes, and
+/// [`UnityClassRegistry::load_str`] picks them out of multi-object dumps.
+#[derive(Debug, Clone)]
+pub struct UnityClassRegistry {
+    classes: Vec<(i32, String, Parser)>,
+}
+
+impl UnityClassRegistry {
+    /// A registry containing the classes of this crate, `NavMeshData` (238) and
+    /// `NavMeshProjectSettings` (126).
+    pub fn new() -> UnityClassRegistry {
+        let mut registry = UnityClassRegistry {
+            classes: Vec::new(),
+        };
+        registry.register::<NavMeshData>(238, "NavMeshData");
+        registry.register::<NavMeshProjectSettings>(126, "NavMeshProjectSettings");
+        registry
+    }
+
+    /// Loads objects with `class_id` and `type_name` as `T`, replacing any type
+    /// registered for them before.
+    ///
+    /// The serde name of `T` must be `type_name`, use `#[serde(rename = "...")]` for
+    /// shared classes such as `MonoBehaviour`.
+    pub fn register<T>(&mut self, class_id: i32, type_name: impl Into<String>)
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let type_name = type_name.into();
+        let parser = parse::<T> as Parser;
+        match self
+            .classes
+            .iter_mut()
+            .find(|(id, name, _)| *id == class_id && *name == type_name)
+        {
+            Some(class) => class.2 = parser,
+            None => self.classes.push((class_id, type_name, parser)),
+        }
+    }
+
+    /// The class id registered for `type_name`, the first one if there are several.
+    pub fn class_id(&self, type_name: &str) -> Option<i32> {
+        self.classes
+            .iter()
+            .find(|(_, name, _)| name == type_name)
+            .map(|(id, _, _)| *id)
+    }
+
+    pub fn contains(&self, class_id: i32, type_name: &str) -> bool {
+        self.find(class_id, type_name).is_some()
+    }
+
+    /// Deserializes `object` as its registered type, `None` if there is none.
+    pub fn parse(&self, object: &UnityObject<'_>) -> Option<Result<Box<dyn Any + Send>>> {
+        self.find(object.class_id, object.type_name)
+            .map(|parser| parser(object))
+    }
+
+    /// Deserializes every object of `data` whose class is registered, in file order.
+    pub fn load_str(&self, data: &str) -> Result<Vec<LoadedObject>> {
+        span!(DEBUG, "load_str", bytes = data.len());
+        let mut loaded = Vec::new();
+        for object in super::objects(data) {
+            let object = object?;
+            if let Some(value) = self.parse(&object) {
+                loaded.push(LoadedObject {
+                    id: object.id,
+                    class_id: object.class_id,
+                    value: value?,
+                });
+            }
+        }
+        Ok(loaded)
+    }
+
+    pub fn len(&self) -> usize {
+        self.classes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+
+    fn find(&self, class_id: i32, type_name: &str) -> Option<Parser> {
+        self.classes
+            .iter()
+            .find(|(id, name, _)| *id == class_id && name == type_name)
+            .map(|(_, _, parser)| *parser)
+    }
+}
+
+impl Default for UnityClassRegistry {
+    fn default() -> Self {
+        UnityClassRegistry::new()
+    }
+}
+
+/// An object loaded by [`UnityClassRegistry::load_str`].
+#[derive(Debug)]
+pub struct LoadedObject {
+    pub id: i64,
+    pub class_id: i32,
+    pub value: Box<dyn Any + Send>,
+}
+
+impl LoadedObject {
+    /// The value, if it was registered as `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+}
@@ -0,0 +1,565 @@
+use super::{Result, UnityClassRegistry, UnityDeError, UnityFloat};
+use serde::ser::{self, Error, Impossible, Serialize};
+use std::fmt::Display;
+use std::fmt::Write;
+
+/// Number of values Unity writes per row of a packed primitive vector.
+const ARRAY_COLUMNS: usize = 25;
+
+#[derive(Clone, Copy)]
+enum Key {
+    Root,
+    Field(&'static str),
+    Data,
+    /// element of a fixed size array, `bytes[0]`
+    Element(&'static str, usize),
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Key::Root => Ok(()),
+            Key::Field(name) => f.write_str(name),
+            Key::Data => f.write_str("data"),
+            Key::Element(name, i) => write!(f, "{}[{}]", name, i),
+        }
+    }
+}
+
+/// How scalar values are laid out at the current position.
+#[derive(Clone, Copy)]
+enum Mode {
+    /// `name value (type)` on a line of its own
+    Line,
+    /// element of a primitive vector, `data (type) #index: v v v ...`
+    Packed(usize),
+    /// component of an inline value such as `(x y z)`
+    Inline(usize),
+}
+
+pub struct UnitySerializer {
+    output: String,
+    tab: usize,
+    key: Key,
+    mode: Mode,
+    /// object id and class id of the `ID: N (ClassID: C)` line
+    header: Option<(i64, i32)>,
+}
+
+/// Writes `value` in the text format produced by Unity's binary-to-text dump, which
+/// `from_str` reads back.
+///
+/// The class id of the object is the one [`UnityClassRegistry::new`] knows for its type
+/// name, 0 for other types, and its id is Unity's `class id * 100000` of the main
+/// object of an asset. Use [`to_string_with_header`] to choose them.
+pub fn to_string<T>(value: &T) -> Result<String>
+where
+    T: Serialize + ?Sized,
+{
+    let mut serializer = UnitySerializer::new();
+    value.serialize(&mut serializer)?;
+    Ok(serializer.output)
+}
+
+/// Like [`to_string`], with `id` and `class_id` in the object header line.
+pub fn to_string_with_header<T>(value: &T, id: i64, class_id: i32) -> Result<String>
+where
+    T: Serialize + ?Sized,
+{
+    let mut serializer = UnitySerializer::with_header(id, class_id);
+    value.serialize(&mut serializer)?;
+    Ok(serializer.output)
+}
+
+impl UnitySerializer {
+    pub fn new() -> Self {
+        UnitySerializer {
+            output: String::new(),
+            tab: 0,
+            key: Key::Root,
+            mode: Mode::Line,
+            header: None,
+        }
+    }
+
+    pub fn with_header(id: i64, class_id: i32) -> Self {
+        UnitySerializer {
+            header: Some((id, class_id)),
+            ..UnitySerializer::new()
+        }
+    }
+
+    pub fn into_inner(self) -> String {
+        self.output
+    }
+
+    fn begin_line(&mut self) -> Result<()> {
+        if let Mode::Inline(_) = self.mode {
+            return Err(UnityDeError::custom(format!(
+                "{} can't be written inline",
+                self.key
+            )));
+        }
+        for _ in 0..self.tab {
+            self.output.push('\t');
+        }
+        write!(self.output, "{}", self.key).unwrap();
+        Ok(())
+    }
+
+    fn write_scalar(&mut self, value: impl Display, type_name: &str) -> Result<()> {
+        match self.mode {
+            Mode::Line => {
+                self.begin_line()?;
+                writeln!(self.output, " {} ({})", value, type_name).unwrap();
+            }
+            Mode::Packed(index) => {
+                if index % ARRAY_COLUMNS == 0 {
+                    if index != 0 {
+                        self.output.push('\n');
+                    }
+                    self.begin_line()?;
+                    write!(self.output, " ({}) #{}:", type_name, index).unwrap();
+                }
+                write!(self.output, " {}", value).unwrap();
+                self.mode = Mode::Packed(index + 1);
+            }
+            Mode::Inline(index) => {
+                if index != 0 {
+                    self.output.push(' ');
+                }
+                write!(self.output, "{}", value).unwrap();
+                self.mode = Mode::Inline(index + 1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the `name  (type)` line which opens a nested value.
+    fn begin_compound(&mut self, type_name: &str) -> Result<Mode> {
+        if let Key::Root = self.key {
+            let (id, class_id) = self.header.unwrap_or_else(|| {
+                let class_id = UnityClassRegistry::new().class_id(type_name).unwrap_or(0);
+                (i64::from(class_id) * 100_000, class_id)
+            });
+            writeln!(
+                self.output,
+                "External References\n\n\nID: {} (ClassID: {}) {}",
+                id, class_id, type_name
+            )
+            .unwrap();
+        } else {
+            self.begin_line()?;
+            writeln!(self.output, "  ({})", type_name).unwrap();
+        }
+        self.tab += 1;
+        Ok(std::mem::replace(&mut self.mode, Mode::Line))
+    }
+
+    fn end_compound(&mut self, mode: Mode) {
+        self.tab -= 1;
+        self.mode = mode;
+        if self.tab == 0 {
+            self.output.push_str("\n\n");
+        }
+    }
+
+    fn unsupported(&self, what: &str) -> UnityDeError {
+        UnityDeError::custom(format!("{} is not supported for {}", what, self.key))
+    }
+}
+
+impl Default for UnitySerializer {
+    fn default() -> Self {
+        UnitySerializer::new()
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut UnitySerializer {
+    type Ok = ();
+    type Error = UnityDeError;
+    type SerializeSeq = SeqSerializer<'a>;
+    type SerializeTuple = TupleStructSerializer<'a>;
+    type SerializeTupleStruct = TupleStructSerializer<'a>;
+    type SerializeTupleVariant = Impossible<(), UnityDeError>;
+    type SerializeMap = MapSerializer<'a>;
+    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), UnityDeError>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.write_scalar(v, "bool")
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.write_scalar(v, "SInt8")
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.write_scalar(v, "SInt16")
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.write_scalar(v, "int")
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.write_scalar(v, "SInt64")
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.write_scalar(v, "UInt8")
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.write_scalar(v, "UInt16")
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.write_scalar(v, "unsigned int")
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.write_scalar(v, "UInt64")
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.write_scalar(UnityFloat(v), "float")
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.write_scalar(v, "double")
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        // written bare, a space or line break would end the value
+        if v.is_whitespace() || v.is_control() {
+            if let Mode::Line = self.mode {
+                return self.serialize_str(v.encode_utf8(&mut [0; 4]));
+            }
+            return Err(UnityDeError::custom(format!(
+                "{:?} can't be written for {}",
+                v, self.key
+            )));
+        }
+        self.write_scalar(v, "char")
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        if v.contains(['\n', '\r']) {
+            return Err(UnityDeError::custom(format!(
+                "line break in string {}",
+                self.key
+            )));
+        }
+        self.begin_line()?;
+        writeln!(self.output, " \"{}\" (string)", v).unwrap();
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        use serde::ser::SerializeSeq;
+        let mut seq = self.serialize_seq(Some(v.len()))?;
+        for b in v {
+            seq.serialize_element(b)?;
+        }
+        seq.end()
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        match self.mode {
+            Mode::Line => Ok(()),
+            _ => Err(self.unsupported("None")),
+        }
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Err(self.unsupported("unit"))
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<()> {
+        // a block without anything in it
+        self.begin_line()?;
+        writeln!(self.output, "  ({})", name).unwrap();
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        // Unity keeps enums as the int they are backed by
+        self.write_scalar(variant_index, "int")
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(self.unsupported("enum with data"))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+        let mode = self.begin_compound("vector")?;
+        let size = match len {
+            Some(len) => {
+                self.key = Key::Field("size");
+                self.write_scalar(len, "int")?;
+                None
+            }
+            None => Some(self.output.len()),
+        };
+        self.mode = Mode::Packed(0);
+        Ok(SeqSerializer {
+            ser: self,
+            mode,
+            size,
+            count: 0,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+        self.serialize_tuple_struct("tuple", len)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        let mode = match name {
+            "Vector3f" => {
+                self.begin_line()?;
+                self.output.push_str(" (");
+                std::mem::replace(&mut self.mode, Mode::Inline(0))
+            }
+            _ => self.begin_compound(name)?,
+        };
+        // Unity names the elements of a fixed size array after the array
+        let element = if name == "Hash128" { "bytes" } else { "data" };
+        Ok(TupleStructSerializer {
+            ser: self,
+            name,
+            element,
+            mode,
+            index: 0,
+        })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(self.unsupported("enum with data"))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap> {
+        let mode = self.begin_compound("map")?;
+        let size = match len {
+            Some(len) => {
+                self.key = Key::Field("size");
+                self.write_scalar(len, "int")?;
+                None
+            }
+            None => Some(self.output.len()),
+        };
+        Ok(MapSerializer {
+            ser: self,
+            mode,
+            size,
+            count: 0,
+        })
+    }
+
+    fn serialize_struct(self, name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        let mode = self.begin_compound(name)?;
+        Ok(StructSerializer { ser: self, mode })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(self.unsupported("enum with data"))
+    }
+}
+
+pub struct SeqSerializer<'a> {
+    ser: &'a mut UnitySerializer,
+    mode: Mode,
+    /// where to insert the `size` line when the length wasn't known upfront
+    size: Option<usize>,
+    count: usize,
+}
+
+impl<'a> ser::SerializeSeq for SeqSerializer<'a> {
+    type Ok = ();
+    type Error = UnityDeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.ser.key = Key::Data;
+        self.count += 1;
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        if let Some(pos) = self.size {
+            let line = format!("{}size {} (int)\n", "\t".repeat(self.ser.tab), self.count);
+            self.ser.output.insert_str(pos, &line);
+        }
+        // ends the last packed row, or leaves the blank line Unity puts after other vectors
+        self.ser.output.push('\n');
+        self.ser.end_compound(self.mode);
+        Ok(())
+    }
+}
+
+pub struct MapSerializer<'a> {
+    ser: &'a mut UnitySerializer,
+    mode: Mode,
+    /// where to insert the `size` line when the length wasn't known upfront
+    size: Option<usize>,
+    count: usize,
+}
+
+impl<'a> ser::SerializeMap for MapSerializer<'a> {
+    type Ok = ();
+    type Error = UnityDeError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        // every entry is a `data  (pair)` holding `first` and `second`
+        self.ser.key = Key::Data;
+        self.ser.begin_line()?;
+        self.ser.output.push_str("  (pair)\n");
+        self.ser.tab += 1;
+        self.ser.key = Key::Field("first");
+        self.count += 1;
+        key.serialize(&mut *self.ser)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.ser.key = Key::Field("second");
+        let ret = value.serialize(&mut *self.ser);
+        self.ser.tab -= 1;
+        ret
+    }
+
+    fn end(self) -> Result<()> {
+        if let Some(pos) = self.size {
+            let line = format!("{}size {} (int)\n", "\t".repeat(self.ser.tab), self.count);
+            self.ser.output.insert_str(pos, &line);
+        }
+        self.ser.output.push('\n');
+        self.ser.end_compound(self.mode);
+        Ok(())
+    }
+}
+
+pub struct TupleStructSerializer<'a> {
+    ser: &'a mut UnitySerializer,
+    name: &'static str,
+    element: &'static str,
+    mode: Mode,
+    index: usize,
+}
+
+impl<'a> ser::SerializeTupleStruct for TupleStructSerializer<'a> {
+    type Ok = ();
+    type Error = UnityDeError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        if let Mode::Line = self.ser.mode {
+            self.ser.key = Key::Element(self.element, self.index);
+        }
+        self.index += 1;
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        if let Mode::Inline(_) = self.ser.mode {
+            writeln!(self.ser.output, ") ({})", self.name).unwrap();
+            self.ser.mode = self.mode;
+        } else {
+            self.ser.end_compound(self.mode);
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ser::SerializeTuple for TupleStructSerializer<'a> {
+    type Ok = ();
+    type Error = UnityDeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeTupleStruct::serialize_field(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        ser::SerializeTupleStruct::end(self)
+    }
+}
+
+pub struct StructSerializer<'a> {
+    ser: &'a mut UnitySerializer,
+    mode: Mode,
+}
+
+impl<'a> ser::SerializeStruct for StructSerializer<'a> {
+    type Ok = ();
+    type Error = UnityDeError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.ser.key = Key::Field(key);
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        self.ser.end_compound(self.mode);
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+//! Helpers for tests built on top of this crate.
+//!
+//! The loading and assertion functions panic with a readable message instead of
+//! returning an error, so they can be used directly inside `#[test]` functions.
+//! [`run_corpus`] checks a whole directory of dumps against a stored baseline.
+//!
+//! Only built with the `testing` feature, enable it for the `dev-dependencies`.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::navmesh::NavMeshData;
+use crate::serde::Result;
+
+/// Set this environment variable to rewrite golden files instead of comparing against them.
+pub const BLESS_ENV: &str = "UNITYAI_BLESS";
+
+/// Loads a Unity text dump.
+#[track_caller]
+pub fn load_fixture<T, P>(path: P) -> T
+where
+    T: DeserializeOwned,
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    crate::serde::from_path(path)
+        .unwrap_or_else(|e| panic!("failed to load fixture {}: {}", path.display(), e))
+}
+
+/// Loads a navmesh from either a Unity text dump or, with the `binary` feature, the
+/// compact format written by `NavMeshData::to_bytes`.
+#[track_caller]
+pub fn load_navmesh<P: AsRef<Path>>(path: P) -> NavMeshData {
+    let path = path.as_ref();
+    #[cfg(feature = "binary")]
+    {
+        let data = std::fs::read(path)
+            .unwrap_or_else(|e| panic!("failed to read fixture {}: {}", path.display(), e));
+        if data.starts_with(b"UAIN") {
+            return NavMeshData::from_bytes(&data)
+                .unwrap_or_else(|e| panic!("failed to load fixture {}: {}", path.display(), e));
+        }
+    }
+    load_fixture(path)
+}
+
+/// Asserts two values are the same, allowing numbers to differ by up to `tolerance`.
+///
+/// Both sides are written with [`crate::serde::to_string`] and compared line by line,
+/// the panic message shows the first line which differs.
+#[track_caller]
+pub fn assert_approx_eq<T: Serialize + ?Sized>(left: &T, right: &T, tolerance: f64) {
+    let left = crate::serde::to_string(left).expect("serialize left value");
+    let right = crate::serde::to_string(right).expect("serialize right value");
+    let mut left_lines = left.lines();
+    let mut right_lines = right.lines();
+    let mut line = 0;
+    loop {
+        line += 1;
+        match (left_lines.next(), right_lines.next()) {
+            (None, None) => return,
+            (Some(l), Some(r)) if line_approx_eq(l, r, tolerance) => continue,
+            (l, r) => panic!(
+                "values differ at line {}:\n  left: {}\n right: {}",
+                line,
+                l.unwrap_or("<end>").trim(),
+                r.unwrap_or("<end>").trim()
+            ),
+        }
+    }
+}
+
+fn line_approx_eq(left: &str, right: &str, tolerance: f64) -> bool {
+    if left == right {
+        return true;
+    }
+    // "(x y z)" of a Vector3f splits into "(x", "y" and "z)"
+    fn trim(token: &str) -> &str {
+        token.trim_matches(['(', ')'])
+    }
+    let mut left = left.split_ascii_whitespace().map(trim);
+    let mut right = right.split_ascii_whitespace().map(trim);
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return true,
+            (Some(l), Some(r)) if l == r => continue,
+            (Some(l), Some(r)) => match (l.parse::<f64>(), r.parse::<f64>()) {
+                (Ok(l), Ok(r)) if (l - r).abs() <= tolerance => continue,
+                _ => return false,
+            },
+            _ => return false,
+        }
+    }
+}
+
+/// Compares `actual` with the content of the golden file at `path`.
+///
+/// When [`BLESS_ENV`] is set the file is written instead, which is how golden files are
+/// created and updated after an intended change.
+#[track_caller]
+pub fn assert_golden<P: AsRef<Path>>(path: P, actual: &str) {
+    let path = path.as_ref();
+    if std::env::var_os(BLESS_ENV).is_some() {
+        std::fs::write(path, actual)
+            .unwrap_or_else(|e| panic!("failed to write golden file {}: {}", path.display(), e));
+        return;
+    }
+    let expected = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        panic!(
+            "failed to read golden file {}: {}, run with {}=1 to create it",
+            path.display(),
+            e,
+            BLESS_ENV
+        )
+    });
+    // fixtures may have been checked out with CRLF line endings
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 0;
+    loop {
+        line += 1;
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => return,
+            (Some(e), Some(a)) if e == a => continue,
+            (e, a) => panic!(
+                "{} differs at line {}:\nexpected: {}\n  actual: {}\nrun with {}=1 to update it",
+                path.display(),
+                line,
+                e.unwrap_or("<end>"),
+                a.unwrap_or("<end>"),
+                BLESS_ENV
+            ),
+        }
+    }
+}
+
+/// Outcome of parsing one asset of a corpus.
+#[derive(Debug)]
+pub struct CorpusEntry {
+    /// path relative to the corpus directory, always with `/` separators
+    pub name: String,
+    pub error: Option<String>,
+    pub elapsed: Duration,
+}
+
+impl CorpusEntry {
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Result of [`run_corpus`], entries are sorted by name.
+#[derive(Debug, Default)]
+pub struct CorpusReport {
+    pub entries: Vec<CorpusEntry>,
+}
+
+/// Difference between a [`CorpusReport`] and a stored baseline.
+#[derive(Debug, Default, PartialEq)]
+pub struct CorpusComparison {
+    /// assets which passed in the baseline, or are new, and fail now
+    pub regressions: Vec<String>,
+    /// assets which failed in the baseline and pass now
+    pub fixed: Vec<String>,
+    /// assets listed in the baseline which are gone
+    pub missing: Vec<String>,
+}
+
+impl CorpusComparison {
+    pub fn is_regression(&self) -> bool {
+        !self.regressions.is_empty()
+    }
+}
+
+/// Parses every NavMeshData dump below `dir`, recording the outcome and parse time of each.
+///
+/// Files are parsed one after another so the timings can be compared between runs.
+pub fn run_corpus<P: AsRef<Path>>(dir: P) -> Result<CorpusReport> {
+    let dir = dir.as_ref();
+    let mut paths = Vec::new();
+    collect_dumps(dir, &mut paths)?;
+    paths.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut report = CorpusReport::default();
+    for (path, header) in paths {
+        let start = Instant::now();
+        let result = header.and_then(|_| crate::serde::from_path::<NavMeshData, _>(&path));
+        let elapsed = start.elapsed();
+        let name = path
+            .strip_prefix(dir)
+            .unwrap_or(&path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        report.entries.push(CorpusEntry {
+            name,
+            error: result.err().map(|e| e.to_string()),
+            elapsed,
+        });
+    }
+    Ok(report)
+}
+
+/// Collects the dumps below `dir`, with the error of each file whose header can't be
+/// read so it fails on its own instead of the whole run.
+fn collect_dumps(dir: &Path, paths: &mut Vec<(PathBuf, Result<()>)>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_dumps(&path, paths)?;
+        } else {
+            match crate::navmesh::is_navmesh_dump(&path) {
+                Ok(true) => paths.push((path, Ok(()))),
+                Ok(false) => {}
+                Err(err) => paths.push((path, Err(err))),
+            }
+        }
+    }
+    Ok(())
+}
+
+impl CorpusReport {
+    pub fn failures(&self) -> impl Iterator<Item = &CorpusEntry> {
+        self.entries.iter().filter(|entry| !entry.passed())
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.entries.iter().map(|entry| entry.elapsed).sum()
+    }
+
+    /// The baseline file content, one `pass <name>` or `fail <name>` line per asset.
+    ///
+    /// Timings are left out so the file only changes when an outcome does.
+    pub fn to_baseline(&self) -> String {
+        let mut baseline = String::new();
+        for entry in &self.entries {
+            let status = if entry.passed() { "pass" } else { "fail" };
+            baseline.push_str(status);
+            baseline.push(' ');
+            baseline.push_str(&entry.name);
+            baseline.push('\n');
+        }
+        baseline
+    }
+
+    /// Compares the outcomes with a baseline written by [`CorpusReport::to_baseline`].
+    pub fn compare(&self, baseline: &str) -> CorpusComparison {
+        let mut expected = std::collections::BTreeMap::new();
+        for line in baseline.lines() {
+            let line = line.trim_end();
+            if let Some(name) = line.strip_prefix("pass ") {
+                expected.insert(name, true);
+            } else if let Some(name) = line.strip_prefix("fail ") {
+                expected.insert(name, false);
+            }
+        }
+
+        let mut comparison = CorpusComparison::default();
+        for entry in &self.entries {
+            match (expected.remove(entry.name.as_str()), entry.passed()) {
+                (Some(false), true) => comparison.fixed.push(entry.name.clone()),
+                (Some(true), false) | (None, false) => {
+                    comparison.regressions.push(entry.name.clone())
+                }
+                _ => {}
+            }
+        }
+        comparison.missing = expected.keys().map(|name| name.to_string()).collect();
+        comparison
+    }
+}
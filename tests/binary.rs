@@ -0,0 +1,62 @@
+#![cfg(feature = "binary")]
+
+use std::fs::File;
+use std::io::Read;
+
+use unityai::navmesh::NavMeshData;
+
+fn load(path: &str) -> NavMeshData {
+    let mut file = File::open(path).expect("open file");
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer).expect("read_to_string");
+    unityai::serde::from_str(buffer.as_str()).expect("deserialize NavMeshData")
+}
+
+#[test]
+fn test_round_trip() {
+    for path in &["tests/Obstacle.txt", "tests/Navmesh.asset.txt"] {
+        let data = load(path);
+        let bytes = data.to_bytes().expect("to_bytes");
+        let decoded = NavMeshData::from_bytes(&bytes).expect("from_bytes");
+        assert_eq!(data, decoded);
+    }
+}
+
+#[test]
+fn test_reject_invalid_header() {
+    let mut bytes = load("tests/Obstacle.txt").to_bytes().expect("to_bytes");
+    assert!(NavMeshData::from_bytes(&bytes[..4]).is_err());
+    bytes[4] = 0xff;
+    assert!(NavMeshData::from_bytes(&bytes).is_err());
+    bytes[0] = b'X';
+    assert!(NavMeshData::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_migrate() {
+    use unityai::navmesh::{format_version, migrate, FORMAT_VERSION};
+
+    let data = load("tests/Obstacle.txt");
+    let mut bytes = data.to_bytes().expect("to_bytes");
+    assert_eq!(
+        format_version(&bytes).expect("format_version"),
+        FORMAT_VERSION
+    );
+    assert!(format_version(b"UAIN").is_err());
+
+    let migrated = migrate(&bytes).expect("migrate");
+    assert_eq!(migrated, bytes);
+    assert_eq!(
+        NavMeshData::from_bytes(&migrated).expect("from_bytes"),
+        data
+    );
+
+    // written by a later release
+    bytes[4..8].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+    let err = migrate(&bytes).expect_err("newer version");
+    assert!(
+        err.to_string().contains("unsupported format version"),
+        "{}",
+        err
+    );
+}
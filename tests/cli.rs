@@ -0,0 +1,209 @@
+#![cfg(feature = "cli")]
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use unityai::navmesh::{AutoOffMeshLinkData, HeightMeshData, NavMeshData};
+use unityai::serde::Vector3f;
+use unityai::testing::load_navmesh;
+
+fn unityai(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_unityai"))
+        .args(args)
+        .output()
+        .expect("run unityai")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).expect("utf-8 stdout")
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8(output.stderr.clone()).expect("utf-8 stderr")
+}
+
+/// Writes `data` as a text dump to a temporary file named after `name`.
+fn write_dump(name: &str, data: &NavMeshData) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("unityai-cli-{}.txt", name));
+    std::fs::write(&path, unityai::serde::to_string(data).expect("to_string")).expect("write");
+    path
+}
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().expect("utf-8 path")
+}
+
+#[test]
+fn test_extract_tile() {
+    let output = unityai(&["extract", "tests/Obstacle.txt", "--tile", "0,-1,0"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let data: NavMeshData = unityai::serde::from_str(&stdout(&output)).expect("extracted dump");
+    assert_eq!(data.m_NavMeshTiles.len(), 1);
+    let header = data.m_NavMeshTiles[0].header().expect("tile header");
+    assert_eq!((header.x, header.y, header.layer), (0, -1, 0));
+
+    let output = unityai(&["extract", "tests/Obstacle.txt", "--tile", " -1 , 0 "]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let data: NavMeshData = unityai::serde::from_str(&stdout(&output)).expect("extracted dump");
+    assert_eq!(data.m_NavMeshTiles.len(), 1);
+
+    let output = unityai(&["extract", "tests/Obstacle.txt", "--tile", "5,5"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("no tile at 5,5"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn test_extract_tile_arguments() {
+    for (value, message) in [
+        ("0,x", "0,x: invalid digit"),
+        ("0", "expected x,y or x,y,layer, got 0"),
+        ("0,0,0,0", "expected x,y or x,y,layer, got 0,0,0,0"),
+    ] {
+        let output = unityai(&["extract", "tests/Obstacle.txt", "--tile", value]);
+        assert_eq!(output.status.code(), Some(2), "{}", value);
+        assert!(stderr(&output).contains(message), "{}", stderr(&output));
+    }
+}
+
+#[test]
+fn test_extract_heightmesh_obj() {
+    let output = unityai(&[
+        "extract",
+        "tests/Navmesh.asset.txt",
+        "--heightmesh",
+        "0",
+        "--format",
+        "obj",
+    ]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let obj = stdout(&output);
+    assert_eq!(obj.lines().filter(|l| l.starts_with("v ")).count(), 1610);
+    assert_eq!(obj.lines().filter(|l| l.starts_with("f ")).count(), 1689);
+    for face in obj.lines().filter(|l| l.starts_with("f ")) {
+        for index in face.split(' ').skip(1) {
+            let index: usize = index.parse().expect("face index");
+            assert!((1..=1610).contains(&index), "{}", face);
+        }
+    }
+
+    let output = unityai(&["extract", "tests/Navmesh.asset.txt", "--heightmesh", "1"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("no height mesh 1, the asset has 1"));
+}
+
+#[test]
+fn test_extract_heightmesh_obj_out_of_range() {
+    let mut data = load_navmesh("tests/Obstacle.txt");
+    data.m_HeightMeshes.push(HeightMeshData {
+        m_Vertices: vec![Vector3f::new(0.0, 0.0, 0.0); 3],
+        m_Indices: vec![0, 1, u32::MAX],
+        ..Default::default()
+    });
+    let path = write_dump("out-of-range", &data);
+    let output = unityai(&[
+        "extract",
+        path_str(&path),
+        "--heightmesh",
+        "0",
+        "--format",
+        "obj",
+    ]);
+    std::fs::remove_file(&path).ok();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("index 4294967295 out of range for 3 vertices"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn test_extract_links_obj() {
+    let mut data = load_navmesh("tests/Obstacle.txt");
+    for i in 0..2 {
+        data.m_OffMeshLinks.push(AutoOffMeshLinkData {
+            m_Start: Vector3f::new(i as f32, 0.0, 0.0),
+            m_End: Vector3f::new(i as f32, 1.5, 2.0),
+            ..Default::default()
+        });
+    }
+    let path = write_dump("links", &data);
+    let obj = unityai(&["extract", path_str(&path), "--links", "--format", "obj"]);
+    let text = unityai(&["extract", path_str(&path), "--links"]);
+    std::fs::remove_file(&path).ok();
+
+    assert!(obj.status.success(), "{}", stderr(&obj));
+    assert_eq!(
+        stdout(&obj),
+        "v 0 0 0\nv 0 1.5 2\nl 1 2\nv 1 0 0\nv 1 1.5 2\nl 3 4\n"
+    );
+
+    assert!(text.status.success(), "{}", stderr(&text));
+    let links: NavMeshData = unityai::serde::from_str(&stdout(&text)).expect("extracted dump");
+    assert!(links.m_NavMeshTiles.is_empty());
+    assert!(links.m_HeightMeshes.is_empty());
+    assert_eq!(links.m_OffMeshLinks, data.m_OffMeshLinks);
+}
+
+#[test]
+fn test_validate_json() {
+    let path = std::env::temp_dir().join("unityai-cli-malformed.txt");
+    std::fs::write(
+        &path,
+        "External References\n\n\nID: 1 (ClassID: 238) NavMeshData\n\tm_AgentTypeID x (int)\n\n\n",
+    )
+    .expect("write");
+    let output = unityai(&["validate", path_str(&path), "--format", "json"]);
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_str(&stdout(&output)).expect("json report");
+    assert_eq!(report["asset"], path_str(&path));
+    assert_eq!(report["valid"], false);
+    let errors = report["errors"].as_array().expect("errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["kind"], "parse");
+    assert!(errors[0]["message"].is_string());
+    assert_eq!(errors[0]["line"], 5);
+    assert_eq!(errors[0]["column"], 16);
+
+    let output = unityai(&["validate", "tests/Obstacle.txt", "--format", "json"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let report: serde_json::Value = serde_json::from_str(&stdout(&output)).expect("json report");
+    assert_eq!(report["asset"], "tests/Obstacle.txt");
+    assert_eq!(report["valid"], true);
+    assert_eq!(report["errors"], serde_json::json!([]));
+}
+
+#[test]
+fn test_convert_text() {
+    let output = unityai(&["convert", "tests/Obstacle.txt", "--to", "text"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let data: NavMeshData = unityai::serde::from_str(&stdout(&output)).expect("converted dump");
+    assert_eq!(data, load_navmesh("tests/Obstacle.txt"));
+}
+
+#[test]
+fn test_convert_json() {
+    let output = unityai(&["convert", "tests/Obstacle.txt", "--to", "json"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let data: NavMeshData = serde_json::from_str(&stdout(&output)).expect("converted json");
+    assert_eq!(data, load_navmesh("tests/Obstacle.txt"));
+
+    let mut data = load_navmesh("tests/Obstacle.txt");
+    data.m_NavMeshBuildSettings.agentRadius = f32::NAN;
+    let path = write_dump("nan", &data);
+    let output = unityai(&["convert", path_str(&path), "--to", "json"]);
+    std::fs::remove_file(&path).ok();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(
+        stderr(&output).contains("JSON cannot represent NaN or infinite floats"),
+        "{}",
+        stderr(&output)
+    );
+}
@@ -1,11 +1,11 @@
-#![allow(non_snake_case)]
+#![allow(non_snake_case, dead_code, clippy::upper_case_acronyms)]
 
 use std::fs::File;
 use std::io::Read;
 
 use serde::Deserialize;
 
-use unityai::serde::{Hash128, Vector3f};
+use unityai::serde::{Hash128, Location, UnityDeError, Vector3f};
 
 #[derive(Deserialize, Debug)]
 struct NavMeshData {
@@ -111,3 +111,665 @@ fn test_de() {
             .expect("deserialize NavMeshData");
     println!("data is {:?}", data);
 }
+
+#[derive(Deserialize, Debug)]
+struct PackedBytes {
+    m_Bytes: Vec<u8>,
+    m_Value: i32,
+}
+
+#[test]
+fn test_packed_bytes() {
+    let mut input = String::from("External References\n\n\nID: 1 (ClassID: 1) PackedBytes\n");
+    input.push_str("\tm_Bytes  (vector)\n\t\tsize 30 (int)\n");
+    input.push_str("\t\tdata (UInt8) #0:");
+    for i in 0..25 {
+        input.push_str(&format!(" {}", i));
+    }
+    input.push_str("\n\t\tdata (UInt8) #25: 25 26 27 28 255\n");
+    input.push_str("\tm_Value 7 (int)\n\n\n");
+
+    let data: PackedBytes = unityai::serde::from_str(input.as_str()).expect("deserialize");
+    assert_eq!(data.m_Bytes.len(), 30);
+    assert_eq!(data.m_Bytes[..29], (0..29).collect::<Vec<u8>>()[..]);
+    assert_eq!(data.m_Bytes[29], 255);
+    assert_eq!(data.m_Value, 7);
+}
+
+#[derive(Deserialize, serde::Serialize, Debug, PartialEq)]
+struct Points {
+    m_Points: Vec<Vector3f>,
+    m_Value: i32,
+}
+
+fn parse_error<'a, T: Deserialize<'a> + std::fmt::Debug>(input: &'a str) -> UnityDeError {
+    unityai::serde::from_str::<T>(input).expect_err("should fail")
+}
+
+#[test]
+fn test_errors() {
+    let header = "External References\n\n\nID: 1 (ClassID: 1) ";
+
+    let input = format!("{}PackedBytes\n\tm_Value 7 (int)\n\n\n", header);
+    match parse_error::<Points>(&input) {
+        UnityDeError::TypeMismatch {
+            expected, found, ..
+        } => assert_eq!(
+            (expected.as_str(), found.as_str()),
+            ("Points", "PackedBytes")
+        ),
+        err => panic!("unexpected error {:?}", err),
+    }
+
+    let input = format!("{}Points\n\tm_Value 7x (int)\n\n\n", header);
+    let err = parse_error::<PackedBytes>(&input);
+    assert!(
+        matches!(err, UnityDeError::TypeMismatch { .. }),
+        "{:?}",
+        err
+    );
+    let err = parse_error::<Points>(&input);
+    match &err {
+        UnityDeError::ParseNumber { content, location } => {
+            assert_eq!(content, "7x");
+            assert_eq!(
+                *location,
+                Some(Location {
+                    line: 5,
+                    column: 10
+                })
+            );
+        }
+        err => panic!("unexpected error {:?}", err),
+    }
+    assert_eq!(err.to_string(), "parse '7x' failed at line 5 column 10");
+
+    // a dump cut off in the middle of a value
+    let input = format!(
+        "{}Points\n\tm_Points  (vector)\n\t\tsize 0 (int)\n\n\tm_Value 7",
+        header
+    );
+    let err = parse_error::<Points>(&input);
+    assert!(matches!(err, UnityDeError::Eof { .. }), "{:?}", err);
+    assert_eq!(err.location().map(|l| l.line), Some(8));
+
+    let input = format!(
+        "{}Points\n\tm_Points  (vector)\n\t\tsize 1 (int)\n\tdata (1 2 3) (Vector3f)\n\n\tm_Value 7 (int)\n\n\n",
+        header
+    );
+    let err = parse_error::<Points>(&input);
+    assert!(
+        matches!(
+            err,
+            UnityDeError::TabMismatch {
+                expected: 2,
+                found: 1,
+                ..
+            }
+        ),
+        "{:?}",
+        err
+    );
+    assert_eq!(err.location().map(|l| l.line), Some(7));
+
+    let input = format!(
+        "{}Points\n\tm_Points  (vector)\n\t\tsize 0 (int)\n\n\n\n",
+        header
+    );
+    match parse_error::<Points>(&input) {
+        UnityDeError::MissingField { field, .. } => assert_eq!(field, "m_Value"),
+        err => panic!("unexpected error {:?}", err),
+    }
+
+    let input = format!(
+        "{}Points\n\tm_Points  (vector)\n\t\tsize 0 (int)\n\n\tm_Value 7 (int)\n\n\ntrailing\n",
+        header
+    );
+    match parse_error::<Points>(&input) {
+        UnityDeError::UnexpectedToken {
+            expected, found, ..
+        } => {
+            assert_eq!((expected, found.as_str()), ("end of file", "trailing"))
+        }
+        err => panic!("unexpected error {:?}", err),
+    }
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq)]
+struct Inner {
+    a: i32,
+    b: f32,
+}
+
+#[derive(Deserialize, Debug)]
+struct Recovered {
+    m_Bytes: Vec<u8>,
+    m_Inner: Inner,
+    m_Value: i32,
+    m_Position: Vector3f,
+    m_Count: u32,
+}
+
+#[test]
+fn test_parse_all_errors() {
+    let input = "External References\n\n\nID: 1 (ClassID: 1) Other\n\
+                 \tm_Bytes  (vector)\n\t\tsize 3 (int)\n\t\tdata (UInt8) #0: 1 x 3\n\
+                 \tm_Inner  garbage\n\t\ta 1 (int)\n\t\tb 2 (float)\n\
+                 \tm_Value 7x (int)\n\
+                 \tm_Count 5 (unsigned int)\n\n\n";
+    let (data, errors) = unityai::serde::parse_all_errors::<Recovered>(input);
+    let data = data.expect("value with defaults");
+    assert_eq!(data.m_Bytes, [1, 0, 3]);
+    assert_eq!(data.m_Inner, Inner::default());
+    assert_eq!(data.m_Value, 0);
+    assert_eq!(data.m_Position, Vector3f::default());
+    assert_eq!(data.m_Count, 5);
+
+    let lines: Vec<_> = errors
+        .iter()
+        .map(|err| err.location().map(|l| l.line))
+        .collect();
+    assert_eq!(lines, [Some(4), Some(7), Some(8), Some(11), Some(13)]);
+    assert!(matches!(errors[0], UnityDeError::TypeMismatch { .. }));
+    assert!(matches!(&errors[1], UnityDeError::ParseNumber { content, .. } if content == "x"));
+    assert!(matches!(errors[2], UnityDeError::UnexpectedToken { .. }));
+    assert!(matches!(&errors[3], UnityDeError::ParseNumber { content, .. } if content == "7x"));
+    assert!(matches!(
+        errors[4],
+        UnityDeError::MissingField {
+            field: "m_Position",
+            ..
+        }
+    ));
+
+    let input = "External References\n\n\nID: 1 (ClassID: 1) PackedBytes\n\
+                 \tm_Bytes  (vector)\n\t\tsize 0 (int)\n\n\tm_Value 7 (int)\n\n\n";
+    let (data, errors) = unityai::serde::parse_all_errors::<PackedBytes>(input);
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(data.expect("value").m_Value, 7);
+
+    // errors which can't be recovered from end parsing
+    let (data, errors) = unityai::serde::parse_all_errors::<PackedBytes>("ID: 1");
+    assert!(data.is_none());
+    assert_eq!(errors.len(), 1);
+}
+
+fn seven() -> i32 {
+    7
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Defaulted {
+    a: i32,
+    #[serde(default = "seven")]
+    b: i32,
+    c: Inner,
+}
+
+#[test]
+fn test_parse_all_errors_defaults() {
+    // a field with a serde default isn't an error and keeps its default
+    let input = "External References\n\n\nID: 1 (ClassID: 1) Defaulted\n\
+                 \ta 1 (int)\n\tc  (Inner)\n\t\ta 2 (int)\n\t\tb 3 (float)\n\n\n";
+    let (data, errors) = unityai::serde::parse_all_errors::<Defaulted>(input);
+    assert!(errors.is_empty(), "{:?}", errors);
+    let data = data.expect("value");
+    assert_eq!(data.b, 7);
+    assert_eq!(data.c.a, 2);
+
+    // fields without one are still read as zero, also in nested structs
+    let input = "External References\n\n\nID: 1 (ClassID: 1) Defaulted\n\
+                 \tc  (Inner)\n\t\tb 3 (float)\n\n\n";
+    let (data, errors) = unityai::serde::parse_all_errors::<Defaulted>(input);
+    let data = data.expect("value");
+    assert_eq!((data.a, data.b, data.c.a), (0, 7, 0));
+    let fields: Vec<_> = errors
+        .iter()
+        .map(|err| match err {
+            UnityDeError::MissingField { field, .. } => *field,
+            _ => panic!("{:?}", err),
+        })
+        .collect();
+    assert_eq!(fields, ["a", "a"]);
+}
+
+#[derive(Deserialize, serde::Serialize, Debug, PartialEq)]
+struct Maps {
+    m_Costs: std::collections::HashMap<String, i32>,
+    m_Layers: std::collections::BTreeMap<i32, Points>,
+    m_Value: i32,
+}
+
+#[test]
+fn test_map() {
+    let input = "External References\n\n\nID: 1 (ClassID: 1) Maps\n\
+                 \tm_Costs  (map)\n\t\tsize 2 (int)\n\
+                 \t\tdata  (pair)\n\t\t\tfirst \"Walkable\" (string)\n\t\t\tsecond 1 (int)\n\
+                 \t\tdata  (pair)\n\t\t\tfirst \"Jump\" (string)\n\t\t\tsecond 2 (int)\n\n\
+                 \tm_Layers  (map)\n\t\tsize 1 (int)\n\
+                 \t\tdata  (pair)\n\t\t\tfirst 3 (int)\n\t\t\tsecond  (Points)\n\
+                 \t\t\t\tm_Points  (vector)\n\t\t\t\t\tsize 1 (int)\n\t\t\t\t\tdata (1 2 3) (Vector3f)\n\n\
+                 \t\t\t\tm_Value 4 (int)\n\n\
+                 \tm_Value 7 (int)\n\n\n";
+    let data: Maps = unityai::serde::from_str(input).expect("deserialize");
+    assert_eq!(data.m_Costs.len(), 2);
+    assert_eq!(data.m_Costs["Walkable"], 1);
+    assert_eq!(data.m_Costs["Jump"], 2);
+    let layer = &data.m_Layers[&3];
+    assert_eq!(layer.m_Points, [Vector3f::new(1.0, 2.0, 3.0)]);
+    assert_eq!(layer.m_Value, 4);
+    assert_eq!(data.m_Value, 7);
+
+    // written the same way, entries of the BTreeMap in order
+    let text = unityai::serde::to_string(&data).expect("to_string");
+    let decoded: Maps = unityai::serde::from_str(&text).expect("deserialize");
+    assert_eq!(decoded, data);
+    let empty = input.replace(
+        "\tm_Layers  (map)\n\t\tsize 1 (int)\n",
+        "\tm_Layers  (map)\n\t\tsize 0 (int)\n\n\tm_Ignored  (map)\n\t\tsize 1 (int)\n",
+    );
+    let data: Maps = unityai::serde::from_str(&empty).expect("deserialize");
+    assert!(data.m_Layers.is_empty());
+}
+
+#[derive(Deserialize, serde::Serialize, Debug, PartialEq)]
+struct OptionalCosts {
+    m_Costs: std::collections::BTreeMap<String, Option<i32>>,
+    m_Value: i32,
+}
+
+#[test]
+fn test_map_optional_values() {
+    let mut data = OptionalCosts {
+        m_Costs: Default::default(),
+        m_Value: 7,
+    };
+    data.m_Costs.insert("Jump".into(), None);
+    data.m_Costs.insert("Walkable".into(), Some(1));
+    data.m_Costs.insert("Water".into(), None);
+    let text = unityai::serde::to_string(&data).expect("to_string");
+    assert!(text.contains("\t\t\tfirst \"Jump\" (string)\n\t\tdata  (pair)\n"));
+    let decoded: OptionalCosts = unityai::serde::from_str(&text).expect("deserialize");
+    assert_eq!(decoded, data);
+}
+
+#[derive(Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq)]
+enum Shape {
+    Capsule,
+    Box,
+}
+
+#[derive(Deserialize, serde::Serialize, Debug, PartialEq)]
+struct Obstacle {
+    m_Shape: Shape,
+    m_Shapes: Vec<Shape>,
+}
+
+#[test]
+fn test_enum() {
+    let input = "External References\n\n\nID: 0 (ClassID: 0) Obstacle\n\
+                 \tm_Shape 1 (int)\n\
+                 \tm_Shapes  (vector)\n\t\tsize 3 (int)\n\t\tdata (int) #0: 1 0 1\n\n\n";
+    let data: Obstacle = unityai::serde::from_str(input).expect("deserialize");
+    assert_eq!(data.m_Shape, Shape::Box);
+    assert_eq!(data.m_Shapes, [Shape::Box, Shape::Capsule, Shape::Box]);
+    let text = unityai::serde::to_string(&data).expect("to_string");
+    assert_eq!(text, input);
+
+    let by_name = input.replace("m_Shape 1 (int)", "m_Shape \"Capsule\" (string)");
+    let data: Obstacle = unityai::serde::from_str(&by_name).expect("deserialize");
+    assert_eq!(data.m_Shape, Shape::Capsule);
+
+    // names in a packed row, each followed by the next one
+    let packed = by_name.replace(
+        "data (int) #0: 1 0 1",
+        "data (string) #0: \"Box\" \"Capsule\" \"Box\"",
+    );
+    let data: Obstacle = unityai::serde::from_str(&packed).expect("deserialize");
+    assert_eq!(data.m_Shape, Shape::Capsule);
+    assert_eq!(data.m_Shapes, [Shape::Box, Shape::Capsule, Shape::Box]);
+
+    let unknown = input.replace("m_Shape 1 (int)", "m_Shape 2 (int)");
+    assert!(unityai::serde::from_str::<Obstacle>(&unknown).is_err());
+}
+
+#[derive(Deserialize, serde::Serialize, Debug, PartialEq)]
+struct OptionalSettings {
+    agentTypeID: i32,
+    maxJumpAcrossDistance: Option<f32>,
+    m_Bounds: Option<Points>,
+}
+
+#[test]
+fn test_option() {
+    let input = "External References\n\n\nID: 0 (ClassID: 0) OptionalSettings\n\
+                 \tagentTypeID 1 (int)\n\
+                 \tmaxJumpAcrossDistance 2.5 (float)\n\n\n";
+    let data: OptionalSettings = unityai::serde::from_str(input).expect("deserialize");
+    assert_eq!(data.maxJumpAcrossDistance, Some(2.5));
+    assert_eq!(data.m_Bounds, None);
+    assert_eq!(unityai::serde::to_string(&data).expect("to_string"), input);
+
+    // exported by an older Unity without the field
+    let older = input.replace("\tmaxJumpAcrossDistance 2.5 (float)\n", "");
+    let data: OptionalSettings = unityai::serde::from_str(&older).expect("deserialize");
+    assert_eq!(data.agentTypeID, 1);
+    assert_eq!(data.maxJumpAcrossDistance, None);
+
+    // leaving out an Option isn't an error when collecting errors either
+    let (data, errors) = unityai::serde::parse_all_errors::<OptionalSettings>(&older);
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(data.expect("value").maxJumpAcrossDistance, None);
+
+    let nested = input.replace(
+        "\n\n\n",
+        "\n\tm_Bounds  (Points)\n\t\tm_Points  (vector)\n\t\t\tsize 0 (int)\n\n\t\tm_Value 3 (int)\n\n\n",
+    );
+    let data: OptionalSettings = unityai::serde::from_str(&nested).expect("deserialize");
+    assert_eq!(data.m_Bounds.expect("bounds").m_Value, 3);
+}
+
+/// Decodes through `deserialize_byte_buf` like `serde_bytes::ByteBuf`.
+#[derive(Debug, PartialEq)]
+struct ByteBuf(Vec<u8>);
+
+impl<'de> Deserialize<'de> for ByteBuf {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BytesVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+            type Value = ByteBuf;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("bytes")
+            }
+
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<ByteBuf, E> {
+                Ok(ByteBuf(v))
+            }
+        }
+
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct MeshData {
+    m_MeshData: ByteBuf,
+    m_Empty: ByteBuf,
+    m_Value: i32,
+}
+
+#[test]
+fn test_byte_buf() {
+    let mut input = String::from(
+        "External References\n\n\nID: 1 (ClassID: 1) MeshData\n\tm_MeshData  (vector)\n",
+    );
+    input.push_str("\t\tsize 30 (int)\n\t\tdata (UInt8) #0:");
+    for i in 0..25 {
+        input.push_str(&format!(" {}", i * 10));
+    }
+    input.push_str("\n\t\tdata (UInt8) #25: 25 26 27 28 255\n");
+    input.push_str("\tm_Empty  (vector)\n\t\tsize 0 (int)\n\n\tm_Value 7 (int)\n\n\n");
+
+    let data: MeshData = unityai::serde::from_str(&input).expect("deserialize");
+    let mut expected: Vec<u8> = (0..25).map(|i| i * 10).collect();
+    expected.extend_from_slice(&[25, 26, 27, 28, 255]);
+    assert_eq!(data.m_MeshData, ByteBuf(expected));
+    assert_eq!(data.m_Empty, ByteBuf(Vec::new()));
+    assert_eq!(data.m_Value, 7);
+
+    let err = unityai::serde::from_str::<MeshData>(&input.replace(" 255\n", " 256\n"))
+        .expect_err("out of range");
+    match &err {
+        UnityDeError::ParseNumber { content, location } => {
+            assert_eq!(content, "256");
+            assert_eq!(location.map(|l| (l.line, l.column)), Some((8, 33)));
+        }
+        err => panic!("unexpected error {:?}", err),
+    }
+}
+
+#[derive(Deserialize, serde::Serialize, Debug, PartialEq)]
+struct Range(i32, i32);
+
+#[derive(Deserialize, serde::Serialize, Debug, PartialEq)]
+struct Tuples {
+    m_Extent: [f32; 3],
+    m_Entry: (i32, String),
+    m_Range: Range,
+    m_Ranges: Vec<Range>,
+    m_Hash: Hash128,
+}
+
+#[test]
+fn test_tuple() {
+    let input = "External References\n\n\nID: 0 (ClassID: 0) Tuples\n\
+                 \tm_Extent  (tuple)\n\t\tdata[0] 1 (float)\n\t\tdata[1] 2 (float)\n\t\tdata[2] 3.5 (float)\n\
+                 \tm_Entry  (pair)\n\t\tfirst 4 (int)\n\t\tsecond \"Jump\" (string)\n\
+                 \tm_Range  (Range)\n\t\tdata[0] 5 (int)\n\t\tdata[1] 6 (int)\n\
+                 \tm_Ranges  (vector)\n\t\tsize 1 (int)\n\
+                 \t\tdata  (Range)\n\t\t\tdata[0] 7 (int)\n\t\t\tdata[1] 8 (int)\n\n\
+                 \tm_Hash  (Hash128)\n";
+    let mut input = input.to_string();
+    for i in 0..16 {
+        input.push_str(&format!("\t\tbytes[{}] {} (UInt8)\n", i, i));
+    }
+    input.push_str("\n\n");
+
+    let data: Tuples = unityai::serde::from_str(&input).expect("deserialize");
+    assert_eq!(data.m_Extent, [1.0, 2.0, 3.5]);
+    assert_eq!(data.m_Entry, (4, "Jump".to_string()));
+    assert_eq!(data.m_Range, Range(5, 6));
+    assert_eq!(data.m_Ranges, [Range(7, 8)]);
+    let bytes: Vec<u8> = (0..16).collect();
+    assert_eq!(data.m_Hash.as_bytes(), &bytes[..]);
+
+    let text = unityai::serde::to_string(&data).expect("to_string");
+    // the pair is written with the element names of any other tuple
+    assert_eq!(
+        text,
+        input.replace(
+            "(pair)\n\t\tfirst 4 (int)\n\t\tsecond",
+            "(tuple)\n\t\tdata[0] 4 (int)\n\t\tdata[1]"
+        )
+    );
+
+    let err = unityai::serde::from_str::<Tuples>(
+        &input.replace("(Range)\n\t\tdata[0] 5", "(Other)\n\t\tdata[0] 5"),
+    )
+    .expect_err("type mismatch");
+    assert!(
+        matches!(err, UnityDeError::TypeMismatch { .. }),
+        "{:?}",
+        err
+    );
+}
+
+#[derive(Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq)]
+struct AgentTypeId(i32);
+
+#[derive(Deserialize, serde::Serialize, Debug, PartialEq)]
+struct Agents {
+    agentTypeID: AgentTypeId,
+    m_Agents: Vec<AgentTypeId>,
+}
+
+#[test]
+fn test_newtype() {
+    let input = "External References\n\n\nID: 0 (ClassID: 0) Agents\n\
+                 \tagentTypeID -1372625422 (int)\n\
+                 \tm_Agents  (vector)\n\t\tsize 2 (int)\n\t\tdata (int) #0: 0 7\n\n\n";
+    let data: Agents = unityai::serde::from_str(input).expect("deserialize");
+    assert_eq!(data.agentTypeID, AgentTypeId(-1372625422));
+    assert_eq!(data.m_Agents, [AgentTypeId(0), AgentTypeId(7)]);
+    assert_eq!(unityai::serde::to_string(&data).expect("to_string"), input);
+}
+
+#[derive(Deserialize, serde::Serialize, Debug, PartialEq)]
+struct Marker;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Markers {
+    m_Marker: Marker,
+    m_Skipped: (),
+    m_Markers: Vec<Marker>,
+    m_Value: i32,
+}
+
+#[test]
+fn test_unit() {
+    let input = "External References\n\n\nID: 0 (ClassID: 0) Markers\n\
+                 \tm_Marker  (Marker)\n\
+                 \tm_Skipped  (Settings)\n\t\tm_Flags 3 (int)\n\t\tm_Nested  (Inner)\n\t\t\ta 1 (int)\n\
+                 \tm_Markers  (vector)\n\t\tsize 2 (int)\n\t\tdata  (Marker)\n\t\tdata  (Marker)\n\n\
+                 \tm_Value 7 (int)\n\n\n";
+    let data: Markers = unityai::serde::from_str(input).expect("deserialize");
+    assert_eq!(data.m_Markers, [Marker, Marker]);
+    assert_eq!(data.m_Value, 7);
+
+    let err = unityai::serde::from_str::<Markers>(&input.replace("(Marker)", "(Other)"))
+        .expect_err("type mismatch");
+    assert!(
+        matches!(err, UnityDeError::TypeMismatch { .. }),
+        "{:?}",
+        err
+    );
+
+    #[derive(serde::Serialize)]
+    struct Written {
+        m_Marker: Marker,
+        m_Value: i32,
+    }
+    let written = Written {
+        m_Marker: Marker,
+        m_Value: 7,
+    };
+    assert_eq!(
+        unityai::serde::to_string(&written).expect("to_string"),
+        "External References\n\n\nID: 0 (ClassID: 0) Written\n\tm_Marker  (Marker)\n\tm_Value 7 (int)\n\n\n"
+    );
+}
+
+#[derive(Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+struct Chars {
+    m_Key: char,
+    m_Quoted: char,
+    m_Keys: Vec<char>,
+}
+
+#[test]
+fn test_char() {
+    let input = "External References\n\n\nID: 0 (ClassID: 0) Chars\n\
+                 \tm_Key W (char)\n\tm_Quoted \" \" (string)\n\
+                 \tm_Keys  (vector)\n\t\tsize 3 (int)\n\t\tdata (char) #0: a b é\n\n\n";
+    let data: Chars = unityai::serde::from_str(input).expect("deserialize");
+    assert_eq!(data.m_Key, 'W');
+    assert_eq!(data.m_Quoted, ' ');
+    assert_eq!(data.m_Keys, ['a', 'b', 'é']);
+
+    let err = unityai::serde::from_str::<Chars>(&input.replace("W (char)", "WS (char)"))
+        .expect_err("two characters");
+    match err {
+        UnityDeError::UnexpectedToken {
+            expected, found, ..
+        } => assert_eq!((expected, found.as_str()), ("single character", "WS")),
+        err => panic!("unexpected error {:?}", err),
+    }
+
+    // a space is written quoted, a quote bare
+    let text = unityai::serde::to_string(&data).expect("to_string");
+    assert_eq!(text, input);
+    for c in &['"', '\t', '\u{1}'] {
+        let data = Chars {
+            m_Quoted: *c,
+            ..data.clone()
+        };
+        let text = unityai::serde::to_string(&data).expect("to_string");
+        assert_eq!(
+            unityai::serde::from_str::<Chars>(&text).expect("deserialize"),
+            data
+        );
+    }
+
+    // a space in a packed row would end the value
+    let data = Chars {
+        m_Keys: vec![' '],
+        ..data
+    };
+    assert!(unityai::serde::to_string(&data).is_err());
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Known {
+    m_Value: i32,
+}
+
+#[test]
+fn test_ignored_any() {
+    let data = std::fs::read_to_string("tests/Obstacle.txt").expect("read fixture");
+    unityai::serde::from_str::<serde::de::IgnoredAny>(&data).expect("ignore the object");
+
+    // unknown fields are skipped line by line, however deep they nest
+    let mut input =
+        String::from("External References\n\n\nID: 1 (ClassID: 1) Known\n\tm_Deep  (Foo)\n");
+    for depth in 2..3000 {
+        input.push_str(&"\t".repeat(depth));
+        input.push_str("x  (Foo)\n");
+    }
+    input.push_str(
+        "\tm_Extra  (Bar)\n\t\tm_List  (vector)\n\t\t\tsize 1 (int)\n\t\t\tdata 1 (int)\n\n\
+                    \t\tm_Bytes  (vector)\n\t\t\tsize 2 (int)\n\t\t\tdata (UInt8) #0: 1 2\n\
+                    \t\tm_Empty  (map)\n\t\t\tsize 0 (int)\n\n\
+                    \t\tm_Other 2 (int)\n\
+                    \tm_Value 7 (int)\n\n\n",
+    );
+    let data: Known = unityai::serde::from_str(&input).expect("deserialize");
+    assert_eq!(data.m_Value, 7);
+
+    // so are malformed lines when collecting errors, blank lines of vectors included
+    let input = "External References\n\n\nID: 1 (ClassID: 1) Known\n\
+                 \tm_Extra garbage\n\t\tm_List  (vector)\n\t\t\tsize 1 (int)\n\t\t\tdata 1 (int)\n\n\
+                 \t\tm_Other 2 (int)\n\
+                 \tm_Value 7 (int)\n\n\n";
+    let (data, errors) = unityai::serde::parse_all_errors::<Known>(input);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(data, Some(Known { m_Value: 7 }));
+}
+
+#[test]
+fn test_from_path() {
+    use unityai::navmesh::NavMeshData;
+
+    let text = std::fs::read_to_string("tests/Obstacle.txt").expect("read fixture");
+    let data: NavMeshData = unityai::serde::from_path("tests/Obstacle.txt").expect("from_path");
+    assert_eq!(data, unityai::serde::from_str(&text).expect("from_str"));
+    assert!(unityai::serde::from_path::<NavMeshData, _>("tests/missing.txt").is_err());
+
+    // the fixture isn't changed while the tests run
+    let mapped: NavMeshData =
+        unsafe { unityai::serde::from_path_mmap("tests/Obstacle.txt") }.expect("from_path_mmap");
+    assert_eq!(mapped, data);
+}
+
+#[derive(Deserialize, Debug)]
+struct Links {
+    m_Links: Vec<Inner>,
+}
+
+#[test]
+fn test_parse_all_errors_many_missing() {
+    // the same field missing from every element is learned once, not per element
+    let mut input = String::from(
+        "External References\n\n\nID: 1 (ClassID: 1) Links\n\tm_Links  (vector)\n\t\tsize 2000 (int)\n",
+    );
+    for _ in 0..2000 {
+        input.push_str("\t\tdata  (Inner)\n\t\t\tb 1.5 (float)\n");
+    }
+    input.push_str("\n\n\n");
+    let (data, errors) = unityai::serde::parse_all_errors::<Links>(&input);
+    let data = data.expect("value");
+    assert_eq!(data.m_Links.len(), 2000);
+    assert!(data.m_Links.iter().all(|link| link.a == 0 && link.b == 1.5));
+    assert_eq!(errors.len(), 2000);
+}
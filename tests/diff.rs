@@ -0,0 +1,32 @@
+use unityai::navmesh::{NavMeshData, TileCoord};
+
+fn load() -> NavMeshData {
+    unityai::serde::from_path("tests/Obstacle.txt").expect("from_path")
+}
+
+#[test]
+fn test_diff_identical() {
+    let old = load();
+    let new = load();
+    assert!(old.diff(&new).is_empty());
+}
+
+#[test]
+fn test_diff() {
+    let old = load();
+    let mut new = load();
+    new.m_NavMeshBuildSettings.agentRadius = 0.25;
+    new.m_NavMeshTiles.remove(0);
+    let last = new.m_NavMeshTiles.len() - 1;
+    *new.m_NavMeshTiles[last].m_MeshData.last_mut().unwrap() ^= 1;
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.settings.len(), 1);
+    assert_eq!(diff.settings[0].name, "agentRadius");
+    assert_eq!(diff.settings[0].new, "0.25");
+    let coord = |x, y| TileCoord { x, y, layer: 0 };
+    assert_eq!(diff.removed_tiles, vec![coord(-1, -1)]);
+    assert_eq!(diff.modified_tiles, vec![coord(0, 0)]);
+    assert!(diff.added_tiles.is_empty());
+    assert!(!diff.is_empty());
+}
@@ -0,0 +1,44 @@
+#![cfg(any(feature = "glam", feature = "mint", feature = "nalgebra"))]
+
+use unityai::serde::{Quaternionf, Vector3f};
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_glam() {
+    let v = glam::Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(glam::Vec3::from(Vector3f::from(v)), v);
+    let q = glam::Quat::from_xyzw(0.0, 0.0, 0.0, 1.0);
+    assert_eq!(glam::Quat::from(Quaternionf::from(q)), q);
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn test_mint() {
+    let v = mint::Vector3 {
+        x: 1.0f32,
+        y: 2.0,
+        z: 3.0,
+    };
+    assert_eq!(mint::Vector3::<f32>::from(Vector3f::from(v)), v);
+    let q = mint::Quaternion {
+        v: mint::Vector3 {
+            x: 0.5f32,
+            y: 0.0,
+            z: 0.0,
+        },
+        s: 1.0,
+    };
+    assert_eq!(mint::Quaternion::<f32>::from(Quaternionf::from(q)), q);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra() {
+    let v = nalgebra::Vector3::new(1.0f32, 2.0, 3.0);
+    assert_eq!(nalgebra::Vector3::<f32>::from(Vector3f::from(v)), v);
+    let q = nalgebra::Quaternion::new(1.0f32, 0.5, 0.0, 0.0);
+    let unity = Quaternionf::from(q);
+    assert_eq!(unity.w, 1.0);
+    assert_eq!(unity.x, 0.5);
+    assert_eq!(nalgebra::Quaternion::<f32>::from(unity), q);
+}
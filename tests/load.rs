@@ -0,0 +1,49 @@
+#[test]
+fn test_load_dir() {
+    let report = unityai::navmesh::load_dir("tests").expect("load_dir");
+    assert!(report.errors.is_empty(), "{:?}", report.errors);
+    let names: Vec<_> = report
+        .navmeshes
+        .iter()
+        .map(|(path, _)| path.file_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["CSZ.asset.txt", "Navmesh.asset.txt", "Obstacle.txt"]
+    );
+}
+
+#[test]
+fn test_is_navmesh_dump() {
+    use unityai::navmesh::is_navmesh_dump;
+
+    assert!(is_navmesh_dump("tests/Obstacle.txt".as_ref()).expect("read"));
+    assert!(!is_navmesh_dump("tests/NavMeshAreas.asset.txt".as_ref()).expect("read"));
+
+    //a long 'External References' section pushes the header far into the file
+    let obstacle = std::fs::read_to_string("tests/Obstacle.txt").expect("read");
+    let mut text = String::from("External References\n");
+    for i in 0..2000 {
+        text.push_str(&format!(
+            "path({}): \"Assets/Scenes/Level{}.unity\"\n",
+            i, i
+        ));
+    }
+    text.push_str(obstacle.trim_start_matches("External References\n"));
+    let path = std::env::temp_dir().join("unityai-load-long-references.txt");
+    std::fs::write(&path, &text).expect("write");
+    let result = is_navmesh_dump(&path);
+    std::fs::remove_file(&path).ok();
+    assert!(result.expect("read"));
+
+    //only the first object decides
+    let path = std::env::temp_dir().join("unityai-load-second-object.txt");
+    std::fs::write(
+        &path,
+        "External References\n\n\nID: 1 (ClassID: 126) NavMeshProjectSettings\n\nID: 2 (ClassID: 238) NavMeshData\n",
+    )
+    .expect("write");
+    let result = is_navmesh_dump(&path);
+    std::fs::remove_file(&path).ok();
+    assert!(!result.expect("read"));
+}
@@ -0,0 +1,127 @@
+#![allow(non_snake_case)]
+
+use serde::Deserialize;
+
+use unityai::navmesh::{NavMeshData, NavMeshProjectSettings};
+use unityai::serde::{UnityClassRegistry, UnityDeError, Vector3f};
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename = "MonoBehaviour")]
+struct WaypointGraph {
+    m_Radius: f32,
+    m_Points: Vec<Vector3f>,
+}
+
+const HEADER: &str = "External References\n\n\n";
+
+const WAYPOINTS: &str = "ID: 5 (ClassID: 114) MonoBehaviour\n\
+                         \tm_Radius 2 (float)\n\
+                         \tm_Points  (vector)\n\t\tsize 1 (int)\n\t\tdata (1 2 3) (Vector3f)\n\n\n";
+
+const GAME_OBJECT: &str = "ID: 6 (ClassID: 1) GameObject\n\tm_Name \"Spawn\" (string)\n\n\n";
+
+/// A scene-like dump: the objects of the fixtures, a custom component and an
+/// object of an unregistered class.
+fn scene() -> String {
+    let mut scene = String::from(HEADER);
+    for path in &["tests/Obstacle.txt", "tests/NavMeshAreas.asset.txt"] {
+        let dump = std::fs::read_to_string(path).expect("read fixture");
+        scene.push_str(dump.strip_prefix(HEADER).expect("fixture header"));
+    }
+    scene.push_str(WAYPOINTS);
+    scene.push_str(GAME_OBJECT);
+    scene
+}
+
+#[test]
+fn test_objects() {
+    let scene = scene();
+    let headers: Vec<_> = unityai::serde::objects(&scene)
+        .map(|object| {
+            let object = object.expect("object header");
+            (object.id, object.class_id, object.type_name)
+        })
+        .collect();
+    assert_eq!(
+        headers,
+        [
+            (23800000, 238, "NavMeshData"),
+            (1, 126, "NavMeshProjectSettings"),
+            (5, 114, "MonoBehaviour"),
+            (6, 1, "GameObject"),
+        ]
+    );
+
+    let object = unityai::serde::objects(&scene).nth(2).unwrap().unwrap();
+    assert!(object.text().starts_with("ID: 5"));
+    let graph: WaypointGraph = object.deserialize().expect("deserialize");
+    assert_eq!(graph.m_Points, [Vector3f::new(1.0, 2.0, 3.0)]);
+
+    let err = unityai::serde::objects("ID: x (ClassID: 1) GameObject\n")
+        .next()
+        .unwrap()
+        .expect_err("bad header");
+    assert!(
+        matches!(err, UnityDeError::UnexpectedToken { .. }),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn test_registry() {
+    let scene = scene();
+    let mut registry = UnityClassRegistry::new();
+    assert!(registry.contains(238, "NavMeshData"));
+    assert!(!registry.contains(114, "MonoBehaviour"));
+    registry.register::<WaypointGraph>(114, "MonoBehaviour");
+    assert_eq!(registry.len(), 3);
+
+    let loaded = registry.load_str(&scene).expect("load_str");
+    let ids: Vec<_> = loaded.iter().map(|object| object.id).collect();
+    assert_eq!(ids, [23800000, 1, 5]);
+
+    let navmesh: NavMeshData = unityai::serde::from_path("tests/Obstacle.txt").expect("from_path");
+    assert_eq!(loaded[0].downcast_ref::<NavMeshData>(), Some(&navmesh));
+    assert!(loaded[1].downcast_ref::<NavMeshProjectSettings>().is_some());
+    assert!(loaded[1].downcast_ref::<NavMeshData>().is_none());
+    let graph = loaded[2].downcast_ref::<WaypointGraph>().unwrap();
+    assert_eq!(graph.m_Radius, 2.0);
+}
+
+#[test]
+fn test_registry_error_location() {
+    let mut registry = UnityClassRegistry::new();
+    registry.register::<WaypointGraph>(114, "MonoBehaviour");
+    let scene = format!(
+        "{}{}{}",
+        HEADER,
+        WAYPOINTS.replace("2 (float)", "2x (float)"),
+        GAME_OBJECT
+    );
+    let err = registry.load_str(&scene).expect_err("bad value");
+    assert!(matches!(err, UnityDeError::ParseNumber { .. }), "{:?}", err);
+    assert_eq!(err.location().map(|l| l.line), Some(5));
+}
+
+#[test]
+fn test_to_string_header() {
+    let navmesh: NavMeshData = unityai::serde::from_path("tests/Obstacle.txt").expect("from_path");
+    let text = unityai::serde::to_string(&navmesh).expect("to_string");
+    assert!(text.starts_with("External References\n\n\nID: 23800000 (ClassID: 238) NavMeshData\n"));
+    let loaded = UnityClassRegistry::new().load_str(&text).expect("load_str");
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded[0].downcast_ref::<NavMeshData>(), Some(&navmesh));
+
+    let text = unityai::serde::to_string_with_header(&navmesh, 7, 238).expect("to_string");
+    let object = unityai::serde::objects(&text).next().unwrap().unwrap();
+    assert_eq!((object.id, object.class_id), (7, 238));
+
+    // types unknown to the registry have class id 0
+    #[derive(serde::Serialize)]
+    struct Marker {
+        m_Value: i32,
+    }
+    let text = unityai::serde::to_string(&Marker { m_Value: 1 }).expect("to_string");
+    assert!(text.contains("\nID: 0 (ClassID: 0) Marker\n"), "{}", text);
+}
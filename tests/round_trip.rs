@@ -0,0 +1,54 @@
+use std::fmt::Debug;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use unityai::navmesh::{NavMeshData, NavMeshProjectSettings};
+
+mod support;
+
+const CASES: u64 = 500;
+
+fn check<T: Serialize + DeserializeOwned + Debug>(value: &T) {
+    let text = unityai::serde::to_string(value).expect("to_string");
+    let decoded: T = unityai::serde::from_str(&text)
+        .unwrap_or_else(|e| panic!("from_str failed: {}\n{}", e, text));
+    // Debug output compares NaN equal to itself, which the generator produces
+    assert_eq!(format!("{:?}", value), format!("{:?}", decoded), "{}", text);
+    assert_eq!(
+        text,
+        unityai::serde::to_string(&decoded).expect("to_string")
+    );
+}
+
+#[test]
+fn test_navmesh_round_trip() {
+    for seed in 0..CASES {
+        check::<NavMeshData>(&support::navmesh(seed));
+    }
+}
+
+#[test]
+fn test_project_settings_round_trip() {
+    for seed in 0..CASES {
+        check::<NavMeshProjectSettings>(&support::project_settings(seed));
+    }
+}
+
+#[test]
+fn test_fixture_round_trip() {
+    for path in &[
+        "tests/Obstacle.txt",
+        "tests/Navmesh.asset.txt",
+        "tests/CSZ.asset.txt",
+    ] {
+        let data: NavMeshData = unityai::serde::from_path(path).expect("from_path");
+        check(&data);
+    }
+}
+
+#[test]
+fn test_reject_line_break() {
+    let mut data = support::navmesh(0);
+    data.m_Name = "two\nlines".to_string();
+    assert!(unityai::serde::to_string(&data).is_err());
+}
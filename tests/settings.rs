@@ -0,0 +1,39 @@
+use std::fs::File;
+use std::io::Read;
+
+use unityai::navmesh::{AgentTypeRegistry, NavMeshBuildSettings, NavMeshProjectSettings};
+
+fn load_project_settings() -> NavMeshProjectSettings {
+    let mut file = File::open("tests/NavMeshAreas.asset.txt").expect("open file");
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer).expect("read_to_string");
+    unityai::serde::from_str(buffer.as_str()).expect("deserialize NavMeshProjectSettings")
+}
+
+#[test]
+fn test_humanoid() {
+    let humanoid = NavMeshBuildSettings::humanoid();
+    assert_eq!(humanoid.agentTypeID, 0);
+    assert_eq!(humanoid.agentRadius, 0.5);
+    assert_eq!(humanoid.agentHeight, 2.0);
+
+    let registry = AgentTypeRegistry::new();
+    assert_eq!(registry.len(), 1);
+    assert_eq!(registry.name_of(0), Some("Humanoid"));
+}
+
+#[test]
+fn test_registry_from_project_settings() {
+    let project = load_project_settings();
+    assert_eq!(project.areas.len(), 32);
+    assert_eq!(project.areas[1].name, "Not Walkable");
+
+    let registry = AgentTypeRegistry::from(project);
+    assert_eq!(registry.len(), 2);
+    let critter = registry
+        .get_by_name("Small Critter")
+        .expect("Small Critter");
+    assert_eq!(critter.agentTypeID, -372571969);
+    assert_eq!(critter.agentRadius, 0.25);
+    assert_eq!(registry.get(0).expect("Humanoid").agentClimb, 0.75);
+}
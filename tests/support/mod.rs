@@ -0,0 +1,68 @@
+//! Random model values for round-trip tests, built with the `Arbitrary` impls of the
+//! model from a reproducible stream of bytes.
+#![allow(dead_code)]
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use arbitrary::{Arbitrary, Unstructured};
+use unityai::navmesh::{NavMeshData, NavMeshProjectSettings};
+
+/// Bytes each value is built from, enough for vectors across several packed rows.
+const BYTES: u64 = 4096;
+
+/// Lengths around the 25 values Unity packs into one row of a primitive vector.
+const ROW_LENGTHS: [usize; 6] = [1, 24, 25, 26, 50, 51];
+
+fn bytes(seed: u64) -> Vec<u8> {
+    (0..BYTES / 8)
+        .flat_map(|i| {
+            let mut hasher = DefaultHasher::new();
+            (seed, i).hash(&mut hasher);
+            hasher.finish().to_le_bytes()
+        })
+        .collect()
+}
+
+/// A value built from the bytes of `seed`.
+pub fn arbitrary<T: for<'a> Arbitrary<'a>>(seed: u64) -> T {
+    T::arbitrary(&mut Unstructured::new(&bytes(seed))).expect("arbitrary value")
+}
+
+/// `Arbitrary` picks short vectors, packed ones get lengths across row boundaries.
+fn packed<T: for<'a> Arbitrary<'a>>(u: &mut Unstructured) -> Vec<T> {
+    let len = *u.choose(&ROW_LENGTHS).unwrap_or(&0);
+    (0..len)
+        .map(|_| T::arbitrary(u).expect("arbitrary value"))
+        .collect()
+}
+
+/// Line breaks end a line of the dump and can't be written, they become spaces.
+fn one_line(s: &mut String) {
+    if s.contains(['\n', '\r']) {
+        *s = s.replace(['\n', '\r'], " ");
+    }
+}
+
+pub fn navmesh(seed: u64) -> NavMeshData {
+    let bytes = bytes(seed);
+    let mut u = Unstructured::new(&bytes);
+    let mut data = NavMeshData::arbitrary(&mut u).expect("arbitrary value");
+    one_line(&mut data.m_Name);
+    for tile in &mut data.m_NavMeshTiles {
+        tile.m_MeshData = packed(&mut u);
+    }
+    for mesh in &mut data.m_HeightMeshes {
+        mesh.m_Indices = packed(&mut u);
+    }
+    data
+}
+
+pub fn project_settings(seed: u64) -> NavMeshProjectSettings {
+    let mut settings: NavMeshProjectSettings = arbitrary(seed);
+    for area in &mut settings.areas {
+        one_line(&mut area.name);
+    }
+    settings.m_SettingNames.iter_mut().for_each(one_line);
+    settings
+}
@@ -0,0 +1,69 @@
+use unityai::navmesh::NavMeshData;
+use unityai::testing::{assert_approx_eq, assert_golden, load_fixture, load_navmesh};
+
+#[test]
+fn test_load_navmesh() {
+    let text: NavMeshData = load_fixture("tests/Obstacle.txt");
+    assert_approx_eq(&text, &load_navmesh("tests/Obstacle.txt"), 0.0);
+
+    #[cfg(feature = "binary")]
+    {
+        let path = std::env::temp_dir().join("unityai-testing-obstacle.bin");
+        std::fs::write(&path, text.to_bytes().expect("to_bytes")).expect("write");
+        let compact = load_navmesh(&path);
+        std::fs::remove_file(&path).ok();
+        assert_approx_eq(&text, &compact, 0.0);
+    }
+}
+
+#[test]
+#[should_panic(expected = "tests/missing.txt")]
+fn test_load_missing_fixture() {
+    load_navmesh("tests/missing.txt");
+}
+
+#[test]
+fn test_approx_eq() {
+    let data = load_navmesh("tests/Obstacle.txt");
+    let mut moved = load_navmesh("tests/Obstacle.txt");
+    moved.m_NavMeshBuildSettings.agentRadius += 0.001;
+    assert_approx_eq(&data, &moved, 0.01);
+    let result = std::panic::catch_unwind(|| assert_approx_eq(&data, &moved, 0.0001));
+    let message = *result
+        .expect_err("should differ")
+        .downcast::<String>()
+        .unwrap();
+    assert!(message.contains("agentRadius"), "{}", message);
+}
+
+#[test]
+fn test_golden() {
+    let data = load_navmesh("tests/Obstacle.txt");
+    let text = unityai::serde::to_string(&data).expect("to_string");
+    assert_golden("tests/golden/Obstacle.txt", &text);
+}
+
+#[test]
+fn test_corpus() {
+    let report = unityai::testing::run_corpus("tests").expect("run_corpus");
+    let names: Vec<_> = report.entries.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "CSZ.asset.txt",
+            "Navmesh.asset.txt",
+            "Obstacle.txt",
+            "golden/Obstacle.txt"
+        ]
+    );
+    assert_eq!(report.failures().count(), 0);
+
+    let baseline = report.to_baseline();
+    assert!(baseline.starts_with("pass CSZ.asset.txt\n"));
+    assert!(!report.compare(&baseline).is_regression());
+
+    let comparison = report.compare("fail Obstacle.txt\npass Removed.txt\n");
+    assert_eq!(comparison.fixed, vec!["Obstacle.txt"]);
+    assert_eq!(comparison.missing, vec!["Removed.txt"]);
+    assert!(comparison.regressions.is_empty());
+}
@@ -0,0 +1,27 @@
+use unityai::navmesh::{NavMeshData, TileHeader};
+
+#[test]
+fn test_tile_header() {
+    let data: NavMeshData = unityai::serde::from_path("tests/Obstacle.txt").expect("from_path");
+    let headers: Vec<_> = data
+        .m_NavMeshTiles
+        .iter()
+        .map(|t| t.header().expect("header"))
+        .map(|h| (h.x, h.y, h.layer))
+        .collect();
+    assert_eq!(
+        headers,
+        vec![(-1, -1, 0), (0, -1, 0), (-1, 0, 0), (0, 0, 0)]
+    );
+    assert_eq!(data.m_NavMeshTiles[0].header().map(|h| h.version), Some(16));
+}
+
+#[test]
+fn test_tile_header_invalid() {
+    let mut data: NavMeshData = unityai::serde::from_path("tests/Obstacle.txt").expect("from_path");
+    let tile = &mut data.m_NavMeshTiles[0];
+    tile.m_MeshData.truncate(12);
+    assert_eq!(tile.header(), None::<TileHeader>);
+    tile.m_MeshData.clear();
+    assert_eq!(tile.header(), None);
+}
@@ -0,0 +1,133 @@
+use unityai::serde::{Hash128, Quaternionf, Vector3f, AABB};
+
+#[test]
+fn test_vector3f() {
+    let v = Vector3f::new(1.0, -2.5, 3.0);
+    assert_eq!((v.x(), v.y(), v.z()), (1.0, -2.5, 3.0));
+    assert_eq!(v.to_array(), [1.0, -2.5, 3.0]);
+    assert_eq!(Vector3f::from([1.0, -2.5, 3.0]), v);
+    assert_eq!(Vector3f::from((1.0, -2.5, 3.0)), v);
+    let tuple: (f32, f32, f32) = Vector3f::new(4.0, 5.0, 6.0).into();
+    assert_eq!(tuple, (4.0, 5.0, 6.0));
+    let array: [f32; 3] = v.into();
+    assert_eq!(array, [1.0, -2.5, 3.0]);
+}
+
+#[test]
+fn test_hash128() {
+    let bytes = [7u8; 16];
+    let hash = Hash128::new(bytes);
+    assert_eq!(hash.as_bytes(), &bytes);
+    assert_eq!(hash.as_ref(), &bytes[..]);
+    assert_eq!(Hash128::from(bytes), hash);
+    let back: [u8; 16] = hash.into();
+    assert_eq!(back, bytes);
+}
+
+#[test]
+fn test_quaternion_and_aabb() {
+    let q: [f32; 4] = Quaternionf::identity().into();
+    assert_eq!(q, [0.0, 0.0, 0.0, 1.0]);
+    let q = Quaternionf::from([1.0, 2.0, 3.0, 4.0]);
+    assert_eq!((q.x, q.y, q.z, q.w), (1.0, 2.0, 3.0, 4.0));
+
+    let bounds = AABB::from_min_max(Vector3f::new(-1.0, 0.0, 2.0), Vector3f::new(3.0, 4.0, 6.0));
+    assert_eq!(bounds.m_Center, Vector3f::new(1.0, 2.0, 4.0));
+    assert_eq!(bounds.m_Extent, Vector3f::new(2.0, 2.0, 2.0));
+    assert_eq!(bounds.min(), Vector3f::new(-1.0, 0.0, 2.0));
+    assert_eq!(bounds.max(), Vector3f::new(3.0, 4.0, 6.0));
+}
+
+#[test]
+fn test_model_derives() {
+    use std::collections::HashSet;
+    use unityai::navmesh::{NavMeshBuildSettings, NavMeshData, NavMeshTileData};
+
+    let data = NavMeshData::default();
+    assert_eq!(
+        data.m_NavMeshBuildSettings,
+        NavMeshBuildSettings::humanoid()
+    );
+    assert_eq!(data.m_Rotation, Quaternionf::identity());
+    assert_eq!(data.clone(), data);
+
+    let tile = NavMeshTileData {
+        m_MeshData: vec![1, 2, 3],
+        m_Hash: Hash128::new([1; 16]),
+    };
+    let tiles: HashSet<_> = vec![tile.clone(), tile, NavMeshTileData::default()]
+        .into_iter()
+        .collect();
+    assert_eq!(tiles.len(), 2);
+
+    let v = Vector3f::new(1.0, 2.0, 3.0);
+    let copy = v;
+    assert_eq!(v, copy);
+    assert_eq!(Vector3f::default(), Vector3f::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_display_from_str() {
+    let v = Vector3f::new(-107.789, 24.5829, 0.0);
+    assert_eq!(v.to_string(), "(-107.789 24.5829 0)");
+    assert_eq!(v.to_string().parse::<Vector3f>().unwrap(), v);
+    assert_eq!(
+        "1 0 2".parse::<Vector3f>().unwrap(),
+        Vector3f::new(1.0, 0.0, 2.0)
+    );
+    assert_eq!(
+        " (1.11022e-16 -0 inf) ".parse::<Vector3f>().unwrap(),
+        Vector3f::new(1.11022e-16, -0.0, f32::INFINITY)
+    );
+    assert!("(1 2)".parse::<Vector3f>().is_err());
+    assert!("(1 2 3 4)".parse::<Vector3f>().is_err());
+    assert!("(1 x 3)".parse::<Vector3f>().is_err());
+
+    let mut bytes = [0u8; 16];
+    bytes[0] = 0xab;
+    bytes[15] = 0x01;
+    let hash = Hash128::new(bytes);
+    assert_eq!(hash.to_string(), "ab000000000000000000000000000001");
+    assert_eq!(hash.to_string().parse::<Hash128>().unwrap(), hash);
+    assert_eq!(
+        "AB000000000000000000000000000001"
+            .parse::<Hash128>()
+            .unwrap(),
+        hash
+    );
+    assert!("ab".parse::<Hash128>().is_err());
+    assert!("zz000000000000000000000000000001"
+        .parse::<Hash128>()
+        .is_err());
+
+    assert_eq!(Quaternionf::identity().to_string(), "(0 0 0 1)");
+}
+
+#[test]
+fn test_display_unity_floats() {
+    // written like Unity, with an exponent for very small and very large components
+    let dump = std::fs::read_to_string("tests/Obstacle.txt").expect("read fixture");
+    let line = dump
+        .lines()
+        .find(|line| line.contains("m_Extent"))
+        .expect("m_Extent line");
+    let text = "(25 1.11022e-16 25)";
+    assert!(line.contains(text), "{}", line);
+    assert_eq!(text.parse::<Vector3f>().unwrap().to_string(), text);
+
+    let v = Vector3f::new(1e-45, f32::MAX, 0.1);
+    assert_eq!(v.to_string(), "(1e-45 3.4028235e+38 0.1)");
+    assert_eq!(v.to_string().parse::<Vector3f>().unwrap(), v);
+    assert_eq!(
+        Vector3f::new(0.0001, 8.85209e-05, -123456.7).to_string(),
+        "(0.0001 8.85209e-05 -123456.7)"
+    );
+    assert_eq!(
+        Vector3f::new(1e6, -2.5e-7, f32::NEG_INFINITY).to_string(),
+        "(1e+06 -2.5e-07 -inf)"
+    );
+    assert_eq!(
+        Quaternionf::new(0.0, -1.11022e-16, 0.0, 1.0).to_string(),
+        "(0 -1.11022e-16 0 1)"
+    );
+}
@@ -0,0 +1,44 @@
+use unityai::navmesh::{NavMeshData, ValidationError};
+
+fn load() -> NavMeshData {
+    unityai::serde::from_path("tests/Obstacle.txt").expect("from_path")
+}
+
+#[test]
+fn test_valid() {
+    for path in &[
+        "tests/Obstacle.txt",
+        "tests/CSZ.asset.txt",
+        "tests/Navmesh.asset.txt",
+    ] {
+        let data: NavMeshData = unityai::serde::from_path(path).expect("from_path");
+        assert_eq!(data.validate(), vec![], "{}", path);
+    }
+}
+
+#[test]
+fn test_invalid() {
+    let mut data = load();
+    data.m_AgentTypeID = 5;
+    data.m_NavMeshTiles[1].m_MeshData = data.m_NavMeshTiles[0].m_MeshData.clone();
+    data.m_NavMeshTiles[2].m_MeshData[0] = 0;
+    let errors = data.validate();
+    assert_eq!(
+        errors,
+        vec![
+            ValidationError::AgentTypeMismatch {
+                data: 5,
+                settings: 0
+            },
+            ValidationError::DuplicateTile {
+                tile: 1,
+                first: 0,
+                x: -1,
+                y: -1,
+                layer: 0
+            },
+            ValidationError::InvalidTileHeader { tile: 2 },
+        ]
+    );
+    assert_eq!(errors[2].kind(), "invalid_tile_header");
+}